@@ -0,0 +1,71 @@
+//! ISO 3166-1 country normalization for the `ADR` property's country component.
+//!
+//! `VCardBuilder::address_parts` takes a free-form country string (`"USA"`, `"us"`,
+//! `"United States"`, ...). [`Country::lookup`] normalizes any of those spellings to a
+//! canonical [`Country`] carrying both ISO 3166-1 codes, so the builder can emit the
+//! registered RFC 6350 `CC=` ADR parameter (Section 6.3.1) instead of leaving the country
+//! as ambiguous free text.
+
+/// A normalized ISO 3166-1 country: its alpha-2 code, alpha-3 code, and common short name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Country {
+    /// ISO 3166-1 alpha-2 code (e.g. `"US"`), the form RFC 6350's `CC=` parameter uses.
+    pub alpha2: &'static str,
+    /// ISO 3166-1 alpha-3 code (e.g. `"USA"`).
+    pub alpha3: &'static str,
+    /// The country's common English short name (e.g. `"United States"`).
+    pub name: &'static str,
+}
+
+impl Country {
+    /// Look up a country by alpha-2 code, alpha-3 code, or common name, case-insensitively.
+    /// Returns `None` if `input` doesn't match any registered country, e.g. because it's
+    /// already free text rather than a recognized country.
+    pub fn lookup(input: &str) -> Option<Country> {
+        let needle = input.trim();
+        if needle.is_empty() {
+            return None;
+        }
+        COUNTRIES
+            .iter()
+            .copied()
+            .find(|c| c.alpha2.eq_ignore_ascii_case(needle) || c.alpha3.eq_ignore_ascii_case(needle) || c.name.eq_ignore_ascii_case(needle))
+    }
+}
+
+/// A subset of the ISO 3166-1 registry covering the countries vCard address books most
+/// commonly reference.
+const COUNTRIES: &[Country] = &[
+    Country { alpha2: "AU", alpha3: "AUS", name: "Australia" },
+    Country { alpha2: "AT", alpha3: "AUT", name: "Austria" },
+    Country { alpha2: "BE", alpha3: "BEL", name: "Belgium" },
+    Country { alpha2: "BR", alpha3: "BRA", name: "Brazil" },
+    Country { alpha2: "CA", alpha3: "CAN", name: "Canada" },
+    Country { alpha2: "CN", alpha3: "CHN", name: "China" },
+    Country { alpha2: "DK", alpha3: "DNK", name: "Denmark" },
+    Country { alpha2: "FI", alpha3: "FIN", name: "Finland" },
+    Country { alpha2: "FR", alpha3: "FRA", name: "France" },
+    Country { alpha2: "DE", alpha3: "DEU", name: "Germany" },
+    Country { alpha2: "GR", alpha3: "GRC", name: "Greece" },
+    Country { alpha2: "HK", alpha3: "HKG", name: "Hong Kong" },
+    Country { alpha2: "IN", alpha3: "IND", name: "India" },
+    Country { alpha2: "IE", alpha3: "IRL", name: "Ireland" },
+    Country { alpha2: "IL", alpha3: "ISR", name: "Israel" },
+    Country { alpha2: "IT", alpha3: "ITA", name: "Italy" },
+    Country { alpha2: "JP", alpha3: "JPN", name: "Japan" },
+    Country { alpha2: "MX", alpha3: "MEX", name: "Mexico" },
+    Country { alpha2: "NL", alpha3: "NLD", name: "Netherlands" },
+    Country { alpha2: "NZ", alpha3: "NZL", name: "New Zealand" },
+    Country { alpha2: "NO", alpha3: "NOR", name: "Norway" },
+    Country { alpha2: "PL", alpha3: "POL", name: "Poland" },
+    Country { alpha2: "PT", alpha3: "PRT", name: "Portugal" },
+    Country { alpha2: "RU", alpha3: "RUS", name: "Russian Federation" },
+    Country { alpha2: "SG", alpha3: "SGP", name: "Singapore" },
+    Country { alpha2: "ZA", alpha3: "ZAF", name: "South Africa" },
+    Country { alpha2: "KR", alpha3: "KOR", name: "South Korea" },
+    Country { alpha2: "ES", alpha3: "ESP", name: "Spain" },
+    Country { alpha2: "SE", alpha3: "SWE", name: "Sweden" },
+    Country { alpha2: "CH", alpha3: "CHE", name: "Switzerland" },
+    Country { alpha2: "GB", alpha3: "GBR", name: "United Kingdom" },
+    Country { alpha2: "US", alpha3: "USA", name: "United States" },
+];
@@ -0,0 +1,208 @@
+//! jCard (RFC 7095): the standard JSON representation of a vCard.
+//!
+//! A jCard document is `["vcard", [ property-arrays... ]]`, where each property is
+//! `[name, params, value-type, value...]`, e.g. `["fn", {}, "text", "John Doe"]` or
+//! `["tel", {"type":["home","voice"]}, "uri", "tel:+1-555-1234"]`. A `group.PROP`-prefixed
+//! property (Section 3.3) carries its group as a lowercase `group` param instead of a
+//! prefix on the name. This gives the crate a standards-based interchange format alongside
+//! its native vCard text format, for interop with CardDAV/JMAP servers that speak jCard.
+
+use crate::structured::split_components;
+use crate::{ParseError, VCardObject, VCardParser, VCardProperty};
+use serde_json::{json, Map, Value};
+
+/// Properties whose text value is `;`-separated structured components, and therefore a
+/// nested JSON array rather than a single string in jCard.
+const STRUCTURED_PROPERTIES: &[&str] = &["N", "ADR"];
+
+impl VCardObject {
+    /// Serialize this vCard to its jCard (RFC 7095) JSON representation.
+    pub fn to_jcard(&self) -> Value {
+        let mut names: Vec<&String> = self.properties.keys().collect();
+        names.sort();
+
+        let mut properties = Vec::new();
+        for name in names {
+            for property in &self.properties[name] {
+                properties.push(property_to_jcard(property));
+            }
+        }
+
+        json!(["vcard", properties])
+    }
+}
+
+fn property_to_jcard(property: &VCardProperty) -> Value {
+    let mut params = Map::new();
+    for (key, values) in &property.parameters {
+        // RFC 7095 Section 3.4 lower-cases parameter *names*; values are verbatim, since
+        // plenty (LABEL, LANGUAGE, GEO, TZ, ...) are case-sensitive.
+        let key = key.to_lowercase();
+        let value = if values.len() == 1 {
+            json!(values[0])
+        } else {
+            json!(values)
+        };
+        params.insert(key, value);
+    }
+
+    // RFC 7095 Section 3.3: a grouped property gets a lowercase `group` param, not a
+    // `group.name` prefix on the name element.
+    if let Some(group) = &property.group {
+        params.insert("group".to_string(), json!(group.to_lowercase()));
+    }
+
+    let value = if STRUCTURED_PROPERTIES.contains(&property.name.as_str()) {
+        json!(split_structured_value(&property.value))
+    } else {
+        json!(property.value)
+    };
+
+    json!([property.name.to_lowercase(), Value::Object(params), jcard_value_type(property), value])
+}
+
+/// The jCard `value-type` element (RFC 7095 Section 3.4) for a property: its explicit
+/// `VALUE` parameter if set, else this crate's default for that property (RFC 6350 Section
+/// 6 lists `uri` as the preferred vCard 4.0 type for `TEL`/`URL` and the other
+/// URI-by-default properties below; everything else defaults to `text`).
+fn jcard_value_type(property: &VCardProperty) -> String {
+    if let Some(value) = property.get_parameter("VALUE") {
+        return value.to_lowercase();
+    }
+
+    match property.name.as_str() {
+        "SOURCE" | "PHOTO" | "IMPP" | "GEO" | "LOGO" | "MEMBER" | "SOUND" | "UID" | "KEY" | "FBURL" | "CALADRURI"
+        | "CALURI" | "TEL" | "URL" => "uri",
+        "BDAY" | "ANNIVERSARY" => "date-and-or-time",
+        "REV" => "timestamp",
+        "TZ" => "utc-offset",
+        _ => "text",
+    }
+    .to_string()
+}
+
+fn split_structured_value(value: &str) -> Vec<Value> {
+    split_components(value)
+        .into_iter()
+        .map(|component| {
+            if component.len() > 1 {
+                json!(component)
+            } else {
+                json!(component.first().cloned().unwrap_or_default())
+            }
+        })
+        .collect()
+}
+
+impl VCardParser {
+    /// Parse a jCard (RFC 7095) JSON document into a single `VCardObject`.
+    pub fn parse_jcard(&mut self, jcard: &str) -> Result<VCardObject, ParseError> {
+        let value: Value = serde_json::from_str(jcard)
+            .map_err(|e| ParseError::new(format!("Invalid jCard JSON: {}", e)))?;
+        jcard_to_vcard(&value)
+    }
+}
+
+fn jcard_to_vcard(value: &Value) -> Result<VCardObject, ParseError> {
+    let array = value
+        .as_array()
+        .ok_or_else(|| ParseError::new("jCard must be a 2-element array"))?;
+
+    if array.len() != 2 || array[0].as_str() != Some("vcard") {
+        return Err(ParseError::new("jCard must be [\"vcard\", [properties...]]"));
+    }
+
+    let properties = array[1]
+        .as_array()
+        .ok_or_else(|| ParseError::new("jCard property list must be an array"))?;
+
+    let mut vcard = VCardObject::new();
+    for property_value in properties {
+        vcard.add_property(jcard_property_from_value(property_value)?);
+    }
+
+    Ok(vcard)
+}
+
+fn jcard_property_from_value(value: &Value) -> Result<VCardProperty, ParseError> {
+    let items = value
+        .as_array()
+        .ok_or_else(|| ParseError::new("jCard property must be an array"))?;
+
+    if items.len() < 4 {
+        return Err(ParseError::new(
+            "jCard property must have at least 4 elements: [name, params, type, value]",
+        ));
+    }
+
+    let name = items[0]
+        .as_str()
+        .ok_or_else(|| ParseError::new("jCard property name must be a string"))?;
+    let params = items[1]
+        .as_object()
+        .ok_or_else(|| ParseError::new("jCard property parameters must be an object"))?;
+
+    let mut property = VCardProperty::new(name, jcard_values_to_text(&items[3..]));
+
+    for (key, param_value) in params {
+        // RFC 7095 Section 3.3: a grouped property carries a `group` param rather than a
+        // `group.name` prefix on the name element.
+        if key.eq_ignore_ascii_case("group") {
+            if let Some(group) = param_value.as_str() {
+                property.group = Some(group.to_string());
+            }
+            continue;
+        }
+
+        match param_value {
+            Value::Array(values) => {
+                for v in values {
+                    if let Some(s) = v.as_str() {
+                        property.add_parameter(key, s);
+                    }
+                }
+            }
+            Value::String(s) => property.add_parameter(key, s.clone()),
+            _ => {}
+        }
+    }
+
+    Ok(property)
+}
+
+/// Join the trailing value elements of a jCard property array back into the crate's text
+/// representation. A structured property's value is the single JSON array
+/// `split_structured_value` produced: each element is one `;`-joined component, itself a
+/// nested array when that component has `,`-joined multi-values; a literal `,`/`;`/`\` in
+/// one of those is re-escaped so re-splitting the rebuilt text recovers the same
+/// components. Anything else is one or more flat, already-unescaped values (jCard has no
+/// backslash-escaping of its own) stored as-is and `;`-joined when there are several; a
+/// single value matches what `VCardParser::parse` stores.
+fn jcard_values_to_text(values: &[Value]) -> String {
+    match values {
+        [Value::Array(components)] => components.iter().map(jcard_component_to_text).collect::<Vec<_>>().join(";"),
+        _ => values.iter().map(jcard_scalar_to_text).collect::<Vec<_>>().join(";"),
+    }
+}
+
+/// Render one structured-property component: a nested array is its items `,`-joined
+/// (each escaped); a scalar is the single escaped value.
+fn jcard_component_to_text(component: &Value) -> String {
+    match component {
+        Value::Array(values) => values
+            .iter()
+            .map(|v| crate::escape_value(&jcard_scalar_to_text(v)))
+            .collect::<Vec<_>>()
+            .join(","),
+        other => crate::escape_value(&jcard_scalar_to_text(other)),
+    }
+}
+
+fn jcard_scalar_to_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        Value::Bool(b) => b.to_string(),
+        _ => String::new(),
+    }
+}
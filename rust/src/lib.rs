@@ -19,26 +19,127 @@ use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
 
-/// Parse error type
+use uuid::Uuid;
+
+/// Data-driven negative-test harness (`ui_test`/`compiletest`-style), gated behind the
+/// `testing` feature so it doesn't ship in normal builds of the crate.
+#[cfg(feature = "testing")]
+pub mod testing;
+
+mod version;
+pub use version::{VCardVersion, Version, VersionReq};
+
+mod jcard;
+
+mod structured;
+pub use structured::{Address, StructuredName};
+
+mod validation;
+pub use validation::{ValidationIssue, ValidationLevel};
+
+mod mutt;
+pub use mutt::{parse_mutt_aliases, to_mutt_aliases};
+
+mod country;
+pub use country::Country;
+
+/// A 1-based location within the original vCard source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column (character offset within the line).
+    pub column: usize,
+    /// 0-based byte offset from the start of the source text.
+    pub offset: usize,
+}
+
+/// Parse error type, carrying the offending source position in addition to a message.
+///
+/// `Display` renders a pest-style diagnostic: the `line:column`, the offending source
+/// line, and a caret underline pointing at (or spanning) the bad token. Errors that
+/// aren't tied to a single line (e.g. "no vCard data found") fall back to a flat
+/// `Parse error: <message>` rendering.
 #[derive(Debug, Clone)]
 pub struct ParseError {
     message: String,
+    position: Option<Position>,
+    span: usize,
+    source_line: String,
 }
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Parse error: {}", self.message)
+        let position = match self.position {
+            Some(position) => position,
+            None => return write!(f, "Parse error: {}", self.message),
+        };
+
+        writeln!(
+            f,
+            "Parse error at line {}, column {}: {}",
+            position.line, position.column, self.message
+        )?;
+        writeln!(f, "{}", self.source_line)?;
+        let indent = " ".repeat(position.column.saturating_sub(1));
+        let caret = "^".repeat(self.span.max(1));
+        write!(f, "{}{}", indent, caret)
     }
 }
 
 impl Error for ParseError {}
 
 impl ParseError {
+    /// Create a flat error with no associated source position.
     fn new(message: impl Into<String>) -> Self {
         ParseError {
             message: message.into(),
+            position: None,
+            span: 0,
+            source_line: String::new(),
+        }
+    }
+
+    /// Create an error pinned to a specific source position, with a caret span
+    /// covering `span` characters starting at `position.column`.
+    fn at(
+        message: impl Into<String>,
+        position: Position,
+        span: usize,
+        source_line: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            message: message.into(),
+            position: Some(position),
+            span: span.max(1),
+            source_line: source_line.into(),
         }
     }
+
+    /// The 1-based line the error occurred on, or 0 if the error has no position.
+    pub fn line(&self) -> usize {
+        self.position.map_or(0, |p| p.line)
+    }
+
+    /// The 1-based column the error occurred on, or 0 if the error has no position.
+    pub fn column(&self) -> usize {
+        self.position.map_or(0, |p| p.column)
+    }
+
+    /// The 0-based byte offset the error occurred at, or 0 if the error has no position.
+    pub fn offset(&self) -> usize {
+        self.position.map_or(0, |p| p.offset)
+    }
+
+    /// The number of characters the caret underline spans.
+    pub fn span(&self) -> usize {
+        self.span
+    }
+
+    /// The full position (line, column, offset), if any.
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
 }
 
 /// Represents a vCard property with parameters and value
@@ -47,6 +148,10 @@ pub struct VCardProperty {
     pub name: String,
     pub value: String,
     pub parameters: HashMap<String, Vec<String>>,
+    /// The `group.` prefix before the property name (e.g. `item1` in `item1.EMAIL:...`),
+    /// if any. RFC 6350 Section 3.3 uses this to associate sibling properties, such as a
+    /// `TEL` and the `X-ABLabel` that names it.
+    pub group: Option<String>,
 }
 
 impl VCardProperty {
@@ -56,6 +161,7 @@ impl VCardProperty {
             name: name.into().to_uppercase(),
             value: value.into(),
             parameters: HashMap::new(),
+            group: None,
         }
     }
 
@@ -79,6 +185,128 @@ impl VCardProperty {
     pub fn get_parameters(&self, param_name: &str) -> Option<&Vec<String>> {
         self.parameters.get(&param_name.to_uppercase())
     }
+
+    /// Replace all values of a parameter, adding it if not already present.
+    pub fn set_parameter(&mut self, param_name: impl Into<String>, values: Vec<String>) {
+        self.parameters.insert(param_name.into().to_uppercase(), values);
+    }
+
+    /// Remove a parameter entirely, returning its values if it was present.
+    pub fn remove_parameter(&mut self, param_name: &str) -> Option<Vec<String>> {
+        self.parameters.remove(&param_name.to_uppercase())
+    }
+
+    /// The `group.` prefix on this property (e.g. `item1` in `item1.EMAIL:...`), if any.
+    pub fn get_property_group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    /// This property's `PREF` parameter (RFC 6350 Section 5.3), the 1-100 ranking clients
+    /// use to pick a default when several properties of the same kind are present. Returns
+    /// `None` if there's no `PREF` parameter or it doesn't parse as an integer from 0 to 255.
+    pub fn pref(&self) -> Option<u8> {
+        self.get_parameter("PREF").and_then(|pref| pref.parse().ok())
+    }
+
+    /// This property's `TYPE` parameter values (comma-separated in the source text, one
+    /// `TYPE=` per RFC 6350 grammar but multiple instances are also accepted), each parsed
+    /// into a [`TypeValue`]. Empty if there's no `TYPE` parameter at all.
+    pub fn types(&self) -> Vec<TypeValue> {
+        self.get_parameters("TYPE")
+            .into_iter()
+            .flatten()
+            .map(|value| TypeValue::parse(value))
+            .collect()
+    }
+
+    /// Decode this property's value per its `ENCODING` parameter (vCard 2.1/3.0's
+    /// `QUOTED-PRINTABLE` and `BASE64`/`b`), returning `None` if there is no `ENCODING`
+    /// parameter, it names a scheme we don't recognize, or the value isn't valid for that
+    /// scheme. `value` itself is left untouched so the property still round-trips losslessly.
+    pub fn decoded_value(&self) -> Option<Vec<u8>> {
+        match self.get_parameter("ENCODING")?.to_uppercase().as_str() {
+            "QUOTED-PRINTABLE" => decode_quoted_printable(&self.value),
+            "B" | "BASE64" => decode_base64(&self.value),
+            _ => None,
+        }
+    }
+}
+
+/// Decode an RFC 2045 quoted-printable value: `=XX` hex escapes map to a byte and all
+/// other bytes pass through unchanged. Soft line breaks (a trailing `=` at the end of a
+/// folded physical line) are already resolved by [`VCardParser::unfold_lines`] before the
+/// value reaches here, so a stray trailing `=` with nothing after it is simply dropped.
+fn decode_quoted_printable(value: &str) -> Option<Vec<u8>> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'=' {
+            if i + 2 >= bytes.len() {
+                // Trailing '=' with no following hex digits: a soft line break that
+                // survived unfolding (e.g. the encoded value was the last line).
+                break;
+            }
+            let hi = (bytes[i + 1] as char).to_digit(16)?;
+            let lo = (bytes[i + 2] as char).to_digit(16)?;
+            decoded.push(((hi << 4) | lo) as u8);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    Some(decoded)
+}
+
+/// Decode a standard-alphabet base64 value, ignoring any embedded whitespace left over
+/// from line folding.
+fn decode_base64(value: &str) -> Option<Vec<u8>> {
+    fn digit(c: u8) -> Option<u8> {
+        match c {
+            b'A'..=b'Z' => Some(c - b'A'),
+            b'a'..=b'z' => Some(c - b'a' + 26),
+            b'0'..=b'9' => Some(c - b'0' + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+
+    let cleaned: Vec<u8> = value.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    let trimmed = cleaned.strip_suffix(b"==").or_else(|| cleaned.strip_suffix(b"=")).unwrap_or(&cleaned);
+
+    let mut decoded = Vec::with_capacity(trimmed.len() * 3 / 4 + 3);
+    for chunk in trimmed.chunks(4) {
+        let digits: Vec<u8> = chunk.iter().map(|&b| digit(b)).collect::<Option<_>>()?;
+        match digits.len() {
+            4 => {
+                decoded.push((digits[0] << 2) | (digits[1] >> 4));
+                decoded.push((digits[1] << 4) | (digits[2] >> 2));
+                decoded.push((digits[2] << 6) | digits[3]);
+            }
+            3 => {
+                decoded.push((digits[0] << 2) | (digits[1] >> 4));
+                decoded.push((digits[1] << 4) | (digits[2] >> 2));
+            }
+            2 => {
+                decoded.push((digits[0] << 2) | (digits[1] >> 4));
+            }
+            _ => return None,
+        }
+    }
+
+    Some(decoded)
+}
+
+/// Whether an unfolded-so-far content line ends in an RFC 2045 quoted-printable soft
+/// line break: a lone trailing `=` on a line that declared `ENCODING=QUOTED-PRINTABLE`.
+/// Scoped to that encoding so an unrelated value (or a base64 value, which may
+/// legitimately end in `=` padding) isn't mistaken for a continuation.
+fn ends_with_qp_soft_break(line: &str) -> bool {
+    line.ends_with('=') && !line.ends_with("\\=") && line.to_uppercase().contains("ENCODING=QUOTED-PRINTABLE")
 }
 
 /// Root vCard object
@@ -115,11 +343,30 @@ impl VCardObject {
         self.properties.get(&name.to_uppercase())
     }
 
+    /// Get every property sharing the given `group.` prefix (e.g. `item1`), regardless of
+    /// property name, so a `TEL` and its sibling `X-ABLabel` can be correlated.
+    pub fn get_properties_in_group(&self, group: &str) -> Vec<&VCardProperty> {
+        let group = group.to_lowercase();
+        self.properties
+            .values()
+            .flatten()
+            .filter(|property| property.group.as_deref() == Some(group.as_str()))
+            .collect()
+    }
+
     /// Get the version
     pub fn version(&self) -> Option<&str> {
         self.get_property("VERSION").map(|p| p.value.as_str())
     }
 
+    /// Get the `VERSION` property as a typed [`VCardVersion`], if it's one of the
+    /// recognized vCard spec generations.
+    pub fn vcard_version(&self) -> Option<VCardVersion> {
+        self.version()
+            .and_then(|v| v.parse::<Version>().ok())
+            .and_then(VCardVersion::from_version)
+    }
+
     /// Get the formatted name
     pub fn formatted_name(&self) -> Option<&str> {
         self.get_property("FN").map(|p| p.value.as_str())
@@ -145,6 +392,18 @@ impl VCardObject {
         self.get_properties("TEL")
     }
 
+    /// Get all telephone properties ranked by `PREF` (RFC 6350 Section 5.3): lower `PREF`
+    /// sorts first, and numbers with no `PREF` sort after every ranked one (preserving their
+    /// relative order). Returns an empty `Vec` if there are no `TEL` properties at all.
+    pub fn telephones_by_preference(&self) -> Vec<&VCardProperty> {
+        let mut phones: Vec<&VCardProperty> = match self.telephones() {
+            Some(phones) => phones.iter().collect(),
+            None => return Vec::new(),
+        };
+        phones.sort_by_key(|phone| (phone.pref().is_none(), phone.pref().unwrap_or(0)));
+        phones
+    }
+
     /// Get all email properties
     pub fn emails(&self) -> Option<&Vec<VCardProperty>> {
         self.get_properties("EMAIL")
@@ -154,6 +413,46 @@ impl VCardObject {
     pub fn addresses(&self) -> Option<&Vec<VCardProperty>> {
         self.get_properties("ADR")
     }
+
+    /// Get a mutable reference to the first property with the given name, if present.
+    pub fn get_property_mut(&mut self, name: &str) -> Option<&mut VCardProperty> {
+        self.properties.get_mut(&name.to_uppercase()).and_then(|props| props.first_mut())
+    }
+
+    /// Get a mutable reference to all properties with the given name, if present.
+    pub fn get_properties_mut(&mut self, name: &str) -> Option<&mut Vec<VCardProperty>> {
+        self.properties.get_mut(&name.to_uppercase())
+    }
+
+    /// Get all telephone properties, mutably.
+    pub fn telephones_mut(&mut self) -> Option<&mut Vec<VCardProperty>> {
+        self.get_properties_mut("TEL")
+    }
+
+    /// Get all email properties, mutably.
+    pub fn emails_mut(&mut self) -> Option<&mut Vec<VCardProperty>> {
+        self.get_properties_mut("EMAIL")
+    }
+
+    /// Get all address properties, mutably.
+    pub fn addresses_mut(&mut self) -> Option<&mut Vec<VCardProperty>> {
+        self.get_properties_mut("ADR")
+    }
+
+    /// Set the value of a property, replacing the first existing one with this name or
+    /// adding a new one if none exists yet.
+    pub fn set_property(&mut self, name: &str, value: impl Into<String>) {
+        let name = name.to_uppercase();
+        match self.properties.get_mut(&name) {
+            Some(props) if !props.is_empty() => props[0].value = value.into(),
+            _ => self.add_property(VCardProperty::new(name, value)),
+        }
+    }
+
+    /// Remove all properties with the given name, returning them if any existed.
+    pub fn remove_property(&mut self, name: &str) -> Option<Vec<VCardProperty>> {
+        self.properties.remove(&name.to_uppercase())
+    }
 }
 
 impl Default for VCardObject {
@@ -162,10 +461,70 @@ impl Default for VCardObject {
     }
 }
 
+/// A single logical (already-unfolded) content line together with the position in the
+/// original source where it starts, so parse errors can point back at real source text.
+#[derive(Debug, Clone)]
+struct LineContext {
+    text: String,
+    line_number: usize,
+    offset: usize,
+}
+
+/// How severe a [`Diagnostic`] collected by [`VCardParser::parse_all`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// A hard violation of RFC 6350 structure or cardinality (missing VERSION/FN,
+    /// unparseable property, mismatched BEGIN/END, ...).
+    Error,
+    /// A recoverable issue the parser tolerates but that callers may still want surfaced.
+    /// [`VCardParser::parse_all`] does not emit this severity; duplicate properties, for
+    /// one, are accepted without any diagnostic.
+    Warning,
+}
+
+/// A single problem found while parsing in lenient mode (see [`VCardParser::parse_all`]).
+///
+/// Unlike the first-error-wins [`VCardParser::parse`], lenient parsing keeps going after
+/// a problem so every violation in a document can be reported in one pass.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    error: ParseError,
+    severity: Severity,
+}
+
+impl Diagnostic {
+    fn new(error: ParseError, severity: Severity) -> Self {
+        Diagnostic { error, severity }
+    }
+
+    /// The severity of this diagnostic.
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// The underlying positioned error.
+    pub fn error(&self) -> &ParseError {
+        &self.error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{}: {}", label, self.error)
+    }
+}
+
 /// Parser for vCard format
 pub struct VCardParser {
-    lines: Vec<String>,
+    lines: Vec<LineContext>,
     current_line: usize,
+    collect_errors: bool,
+    accept_versions: VersionReq,
+    detected_version: Option<Version>,
 }
 
 impl VCardParser {
@@ -174,9 +533,28 @@ impl VCardParser {
         VCardParser {
             lines: Vec::new(),
             current_line: 0,
+            collect_errors: false,
+            accept_versions: VersionReq::default(),
+            detected_version: None,
         }
     }
 
+    /// Store a lenient-mode flag. Neither [`VCardParser::parse`] nor
+    /// [`VCardParser::parse_all`] reads it: `parse` always stops at the first violation
+    /// and `parse_all` always keeps going, whatever this is set to.
+    pub fn collect_errors(mut self, enabled: bool) -> Self {
+        self.collect_errors = enabled;
+        self
+    }
+
+    /// Restrict (or widen) which `VERSION` values are accepted. Defaults to
+    /// `2.1 || 3.0 || 4.0`, since address books in the wild emit all three; see
+    /// [`VersionReq::default`].
+    pub fn accept_versions(mut self, req: VersionReq) -> Self {
+        self.accept_versions = req;
+        self
+    }
+
     /// Parse vCards from a string (returns all vCards found)
     pub fn parse(&mut self, vcard_text: &str) -> Result<Vec<VCardObject>, ParseError> {
         self.lines = self.unfold_lines(vcard_text);
@@ -188,7 +566,7 @@ impl VCardParser {
             // Skip empty lines
             while self.current_line < self.lines.len() {
                 let line = &self.lines[self.current_line];
-                if line.trim().is_empty() {
+                if line.text.trim().is_empty() {
                     self.current_line += 1;
                 } else {
                     break;
@@ -199,17 +577,23 @@ impl VCardParser {
                 break;
             }
 
-            let line = &self.lines[self.current_line];
-            if line.eq_ignore_ascii_case("BEGIN:VCARD") {
+            let line = self.lines[self.current_line].clone();
+            if line.text.eq_ignore_ascii_case("BEGIN:VCARD") {
                 self.current_line += 1;
                 let mut vcard = VCardObject::new();
                 self.parse_component(&mut vcard)?;
                 vcards.push(vcard);
             } else {
-                return Err(ParseError::new(format!(
-                    "Expected BEGIN:VCARD but got: {}",
-                    line
-                )));
+                return Err(ParseError::at(
+                    format!("Expected BEGIN:VCARD but got: {}", line.text),
+                    Position {
+                        line: line.line_number,
+                        column: 1,
+                        offset: line.offset,
+                    },
+                    line.text.chars().count(),
+                    line.text.clone(),
+                ));
             }
         }
 
@@ -220,31 +604,176 @@ impl VCardParser {
         Ok(vcards)
     }
 
-    fn unfold_lines(&self, vcard_text: &str) -> Vec<String> {
+    /// Parse vCards from a string in lenient mode, collecting every diagnostic found
+    /// (missing VERSION/FN, malformed properties, mismatched BEGIN/END, ...) instead of
+    /// stopping at the first one. Useful for validating a large address book in one pass.
+    /// Within one card, only the first missing required property is reported (checked in
+    /// the order VERSION, FN, then N for vCard 3.0).
+    pub fn parse_all(&mut self, vcard_text: &str) -> Vec<Diagnostic> {
+        self.lines = self.unfold_lines(vcard_text);
+        self.current_line = 0;
+
+        let mut diagnostics = Vec::new();
+
+        if self.lines.iter().all(|line| line.text.trim().is_empty()) {
+            diagnostics.push(Diagnostic::new(
+                ParseError::new("No vCard data found"),
+                Severity::Error,
+            ));
+            return diagnostics;
+        }
+
+        while self.current_line < self.lines.len() {
+            while self.current_line < self.lines.len()
+                && self.lines[self.current_line].text.trim().is_empty()
+            {
+                self.current_line += 1;
+            }
+
+            if self.current_line >= self.lines.len() {
+                break;
+            }
+
+            let line = self.lines[self.current_line].clone();
+            if line.text.eq_ignore_ascii_case("BEGIN:VCARD") {
+                self.current_line += 1;
+                let mut vcard = VCardObject::new();
+                self.parse_component_lenient(&mut vcard, &mut diagnostics);
+            } else {
+                diagnostics.push(Diagnostic::new(
+                    ParseError::at(
+                        format!("Expected BEGIN:VCARD but got: {}", line.text),
+                        Position {
+                            line: line.line_number,
+                            column: 1,
+                            offset: line.offset,
+                        },
+                        line.text.chars().count(),
+                        line.text.clone(),
+                    ),
+                    Severity::Error,
+                ));
+                self.current_line += 1;
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Lenient counterpart to `parse_component`: records problems as diagnostics and
+    /// skips the offending line instead of aborting the whole parse.
+    fn parse_component_lenient(&mut self, vcard: &mut VCardObject, diagnostics: &mut Vec<Diagnostic>) {
+        self.detected_version = None;
+
+        while self.current_line < self.lines.len() {
+            let info = self.lines[self.current_line].clone();
+
+            if info.text.to_uppercase().starts_with("END:") {
+                let end_type = info.text[4..].to_uppercase();
+                if end_type != "VCARD" {
+                    diagnostics.push(Diagnostic::new(
+                        ParseError::at(
+                            format!(
+                                "Mismatched END tag: expected END:VCARD but got END:{}",
+                                end_type
+                            ),
+                            Position {
+                                line: info.line_number,
+                                column: 1,
+                                offset: info.offset,
+                            },
+                            info.text.chars().count(),
+                            info.text.clone(),
+                        ),
+                        Severity::Error,
+                    ));
+                }
+                self.current_line += 1;
+
+                if let Err(error) = self.validate_vcard(vcard, &info) {
+                    diagnostics.push(Diagnostic::new(error, Severity::Error));
+                }
+
+                return;
+            }
+
+            match self.parse_property(&info) {
+                Ok(property) => {
+                    if property.name == "VERSION" {
+                        if let Err(error) = self.validate_version(&property, &info) {
+                            diagnostics.push(Diagnostic::new(error, Severity::Error));
+                        }
+                    }
+                    vcard.add_property(property);
+                }
+                Err(error) => diagnostics.push(Diagnostic::new(error, Severity::Error)),
+            }
+
+            self.current_line += 1;
+        }
+
+        diagnostics.push(Diagnostic::new(
+            ParseError::new("Unexpected end of input while parsing VCARD"),
+            Severity::Error,
+        ));
+    }
+
+    /// Unfold folded (continuation) lines into logical content lines, tracking the
+    /// physical line number and byte offset each logical line started at.
+    fn unfold_lines(&self, vcard_text: &str) -> Vec<LineContext> {
         let mut unfolded_lines = Vec::new();
-        let lines: Vec<&str> = vcard_text.lines().collect();
+        let bytes = vcard_text.as_bytes();
 
         let mut current_line = String::new();
+        let mut current_line_number = 1;
+        let mut current_offset = 0;
+        let mut byte_offset = 0;
 
-        for line in lines {
+        for (physical_line_number, line) in (1..).zip(vcard_text.lines()) {
             if line.starts_with(' ') || line.starts_with('\t') {
                 // Continuation line - remove leading whitespace and append
                 current_line.push_str(&line[1..]);
+            } else if ends_with_qp_soft_break(&current_line) {
+                // vCard 2.1/3.0 `ENCODING=QUOTED-PRINTABLE` values use RFC 2045's own
+                // soft line break (a trailing `=`) instead of the usual leading-whitespace
+                // folding, so the continuation isn't indented.
+                current_line.pop();
+                current_line.push_str(line);
             } else {
                 if !current_line.is_empty() {
                     let trimmed = current_line.trim();
                     if !trimmed.is_empty() {
-                        unfolded_lines.push(trimmed.to_string());
+                        unfolded_lines.push(LineContext {
+                            text: trimmed.to_string(),
+                            line_number: current_line_number,
+                            offset: current_offset,
+                        });
                     }
                 }
                 current_line = line.to_string();
+                current_line_number = physical_line_number;
+                current_offset = byte_offset;
             }
+
+            // `.lines()` strips the terminator, so recover its real width from the
+            // source: 2 bytes for CRLF, 1 for a bare LF, 0 at the end of input with no
+            // trailing newline. Assuming a constant 1 undercounts every CRLF line.
+            let terminator_len = match bytes.get(byte_offset + line.len()..byte_offset + line.len() + 2) {
+                Some(b"\r\n") => 2,
+                _ if bytes.get(byte_offset + line.len()) == Some(&b'\n') => 1,
+                _ => 0,
+            };
+            byte_offset += line.len() + terminator_len;
         }
 
         if !current_line.is_empty() {
             let trimmed = current_line.trim();
             if !trimmed.is_empty() {
-                unfolded_lines.push(trimmed.to_string());
+                unfolded_lines.push(LineContext {
+                    text: trimmed.to_string(),
+                    line_number: current_line_number,
+                    offset: current_offset,
+                });
             }
         }
 
@@ -252,25 +781,39 @@ impl VCardParser {
     }
 
     fn parse_component(&mut self, vcard: &mut VCardObject) -> Result<(), ParseError> {
+        self.detected_version = None;
+
         while self.current_line < self.lines.len() {
-            let line = &self.lines[self.current_line].clone();
+            let info = self.lines[self.current_line].clone();
 
-            if line.to_uppercase().starts_with("END:") {
-                let end_type = &line[4..].to_uppercase();
+            if info.text.to_uppercase().starts_with("END:") {
+                let end_type = info.text[4..].to_uppercase();
                 if end_type != "VCARD" {
-                    return Err(ParseError::new(format!(
-                        "Mismatched END tag: expected END:VCARD but got END:{}",
-                        end_type
-                    )));
+                    return Err(ParseError::at(
+                        format!(
+                            "Mismatched END tag: expected END:VCARD but got END:{}",
+                            end_type
+                        ),
+                        Position {
+                            line: info.line_number,
+                            column: 1,
+                            offset: info.offset,
+                        },
+                        info.text.chars().count(),
+                        info.text.clone(),
+                    ));
                 }
                 self.current_line += 1;
 
                 // Validate required properties
-                self.validate_vcard(vcard)?;
+                self.validate_vcard(vcard, &info)?;
 
                 return Ok(());
             } else {
-                let property = self.parse_property(line)?;
+                let property = self.parse_property(&info)?;
+                if property.name == "VERSION" {
+                    self.validate_version(&property, &info)?;
+                }
                 vcard.add_property(property);
                 self.current_line += 1;
             }
@@ -279,79 +822,155 @@ impl VCardParser {
         Err(ParseError::new("Unexpected end of input while parsing VCARD"))
     }
 
-    fn parse_property(&self, line: &str) -> Result<VCardProperty, ParseError> {
-        let colon_index = self.find_unquoted_char(line, ':')
-            .ok_or_else(|| ParseError::new(format!("Invalid property line (missing colon): {}", line)))?;
+    fn parse_property(&self, info: &LineContext) -> Result<VCardProperty, ParseError> {
+        let line = info.text.as_str();
+
+        let colon_index = match self.find_unquoted_char(line, ':') {
+            Some(index) => index,
+            None => {
+                let column = line.chars().count() + 1;
+                return Err(ParseError::at(
+                    format!("Invalid property line (missing colon): {}", line),
+                    Position {
+                        line: info.line_number,
+                        column,
+                        offset: info.offset + line.len(),
+                    },
+                    1,
+                    line.to_string(),
+                ));
+            }
+        };
 
         let name_and_params = &line[..colon_index];
-        let value = &line[colon_index + 1..];
-
-        // Unescape value
-        let value = self.unescape_value(value);
+        let raw_value = &line[colon_index + 1..];
 
         // Parse name and parameters
-        let (property_name, params_part) = if let Some(semicolon_index) = self.find_unquoted_char(name_and_params, ';') {
+        let (bare_name, params_part, params_column) = if let Some(semicolon_index) =
+            self.find_unquoted_char(name_and_params, ';')
+        {
             (
-                name_and_params[..semicolon_index].to_uppercase(),
+                &name_and_params[..semicolon_index],
                 Some(&name_and_params[semicolon_index + 1..]),
+                semicolon_index + 2,
             )
         } else {
-            (name_and_params.to_uppercase(), None)
+            (name_and_params, None, 0)
+        };
+
+        // RFC 6350 Section 3.3: a `group.` prefix before the property name (e.g.
+        // `item1.EMAIL`, `item1.X-ABLabel`), used to associate sibling properties such as
+        // a TEL and the X-ABLabel that names it.
+        let (group, property_name) = match bare_name.find('.') {
+            Some(dot_index) => (
+                Some(bare_name[..dot_index].to_lowercase()),
+                bare_name[dot_index + 1..].to_uppercase(),
+            ),
+            None => (None, bare_name.to_uppercase()),
+        };
+
+        // An `ENCODING=QUOTED-PRINTABLE`/`BASE64` value isn't backslash-escaped text —
+        // it's bytes in another encoding — so leave it exactly as written and let
+        // `decoded_value` interpret it; unescaping here would corrupt both the encoded
+        // bytes and any lossless round-trip back to the original source.
+        let has_encoding_param = params_part
+            .is_some_and(|params| params.to_uppercase().contains("ENCODING="));
+
+        // `N` and `ADR` are `;`-structured properties (see the `structured` module): keep
+        // their `\;` and `\,` separators intact here so `as_structured_name`/`as_address`
+        // can tell an escaped literal separator apart from a real component boundary, and
+        // unescape each component themselves once split.
+        let value = if has_encoding_param {
+            raw_value.to_string()
+        } else if matches!(property_name.as_str(), "N" | "ADR") {
+            self.unescape_value_preserving_separators(raw_value)
+        } else {
+            self.unescape_value(raw_value)
         };
 
         let mut property = VCardProperty::new(property_name, value);
+        property.group = group;
 
         if let Some(params) = params_part {
-            self.parse_parameters(params, &mut property)?;
+            self.parse_parameters(params, &mut property, info, params_column)?;
         }
 
         Ok(property)
     }
 
-    fn parse_parameters(&self, params_part: &str, property: &mut VCardProperty) -> Result<(), ParseError> {
+    fn parse_parameters(
+        &self,
+        params_part: &str,
+        property: &mut VCardProperty,
+        info: &LineContext,
+        base_column: usize,
+    ) -> Result<(), ParseError> {
         let parameters = self.split_parameters(params_part);
-
-        for param in parameters {
-            let equals_index = param.find('=')
-                .ok_or_else(|| ParseError::new(format!("Invalid parameter (missing equals): {}", param)))?;
+        // vCard 2.1 (RFC 2426's predecessor) parameters are bare type tokens with no
+        // `=`, e.g. `TEL;HOME;VOICE:...`. Upconvert them into 4.0-style `TYPE=` values
+        // instead of treating the missing `=` as a syntax error.
+        let legacy_bare_types = self.detected_version.is_some_and(|v| v.major < 3);
+
+        for (local_offset, param) in parameters {
+            let equals_index = match param.find('=') {
+                Some(index) => index,
+                None if legacy_bare_types && !param.trim().is_empty() => {
+                    property.add_parameter("TYPE", param.trim().to_lowercase());
+                    continue;
+                }
+                None => {
+                    let column = base_column + local_offset;
+                    return Err(ParseError::at(
+                        format!("Invalid parameter (missing equals): {}", param),
+                        Position {
+                            line: info.line_number,
+                            column,
+                            offset: info.offset + column - 1,
+                        },
+                        param.chars().count(),
+                        info.text.clone(),
+                    ));
+                }
+            };
 
             let param_name = param[..equals_index].to_uppercase();
-            let mut param_value = param[equals_index + 1..].to_string();
-
-            // Remove quotes if present
-            if param_value.starts_with('"') && param_value.ends_with('"') && param_value.len() >= 2 {
-                param_value = param_value[1..param_value.len() - 1].to_string();
-            }
+            let param_value = param[equals_index + 1..].to_string();
 
-            // Handle comma-separated values
+            // Split on unquoted commas; `split_parameter_values` already strips the quotes
+            // themselves as it tracks `in_quotes`, so an unquoted comma inside a quoted
+            // value (e.g. `LABEL="123 Main St, Apt 4"`) isn't mistaken for a separator.
             let values = self.split_parameter_values(&param_value);
             for value in values {
-                property.add_parameter(param_name.clone(), value);
+                property.add_parameter(param_name.clone(), unescape_parameter_value_rfc6868(&value));
             }
         }
 
         Ok(())
     }
 
-    fn split_parameters(&self, params_part: &str) -> Vec<String> {
+    /// Split `;`-separated parameters, tracking each parameter's character offset
+    /// within `params_part` so callers can translate it back into an absolute column.
+    fn split_parameters(&self, params_part: &str) -> Vec<(usize, String)> {
         let mut parameters = Vec::new();
         let mut current = String::new();
         let mut in_quotes = false;
+        let mut start = 0;
 
-        for c in params_part.chars() {
+        for (index, c) in params_part.chars().enumerate() {
             if c == '"' {
                 in_quotes = !in_quotes;
                 current.push(c);
             } else if c == ';' && !in_quotes {
-                parameters.push(current.clone());
+                parameters.push((start, current.clone()));
                 current.clear();
+                start = index + 1;
             } else {
                 current.push(c);
             }
         }
 
         if !current.is_empty() {
-            parameters.push(current);
+            parameters.push((start, current));
         }
 
         parameters
@@ -393,35 +1012,88 @@ impl VCardParser {
     }
 
     fn unescape_value(&self, value: &str) -> String {
-        value
-            .replace("\\n", "\n")
-            .replace("\\N", "\n")
-            .replace("\\;", ";")
-            .replace("\\,", ",")
-            .replace("\\\\", "\\")
+        unescape_value_scanning(value, false)
     }
 
-    fn validate_vcard(&self, vcard: &VCardObject) -> Result<(), ParseError> {
+    /// Like [`Self::unescape_value`], but leaves `\\`, `\;` and `\,` untouched so a structured
+    /// property's component and multi-value separators stay distinguishable from escaped
+    /// literal characters until `as_structured_name`/`as_address` split them.
+    fn unescape_value_preserving_separators(&self, value: &str) -> String {
+        unescape_value_scanning(value, true)
+    }
+
+    /// Validate the VERSION value as soon as it's parsed, so the error can point at the
+    /// `VERSION` line itself rather than the (possibly distant) `END:VCARD` line.
+    fn validate_version(&mut self, property: &VCardProperty, info: &LineContext) -> Result<(), ParseError> {
+        let parsed = property.value.parse::<Version>().ok();
+        let is_accepted = parsed.is_some_and(|version| self.accept_versions.matches(version));
+
+        if is_accepted {
+            // Remember the version so parse_parameters can pick the right parameter
+            // grammar (2.1's bare `TEL;HOME;VOICE:` tokens vs. 3.0/4.0's `TYPE=`).
+            self.detected_version = parsed;
+            return Ok(());
+        }
+
+        let span = property.value.chars().count().max(1);
+        let column = info.text.chars().count().saturating_sub(span) + 1;
+        Err(ParseError::at(
+            format!(
+                "Unsupported vCard version: {}. Accepted versions: {}.",
+                property.value, self.accept_versions
+            ),
+            Position {
+                line: info.line_number,
+                column,
+                offset: info.offset + info.text.len() - property.value.len(),
+            },
+            span,
+            info.text.clone(),
+        ))
+    }
+
+    fn validate_vcard(&self, vcard: &VCardObject, info: &LineContext) -> Result<(), ParseError> {
         // VERSION is required (RFC 6350 Section 6.7.9)
         if vcard.version().is_none() {
-            return Err(ParseError::new(
-                "Missing required VERSION property (RFC 6350 Section 6.7.9). vCard must include VERSION:4.0".to_string()
+            return Err(ParseError::at(
+                "Missing required VERSION property (RFC 6350 Section 6.7.9). vCard must include a VERSION property (e.g. VERSION:4.0)".to_string(),
+                Position {
+                    line: info.line_number,
+                    column: 1,
+                    offset: info.offset,
+                },
+                info.text.chars().count().max(1),
+                info.text.clone(),
             ));
         }
 
-        // Only version 4.0 is supported (per ADR 0004)
-        if let Some(version) = vcard.version() {
-            if version != "4.0" {
-                return Err(ParseError::new(
-                    format!("Unsupported vCard version: {}. Only version 4.0 is supported (see ADR 0004).", version)
-                ));
-            }
+        // FN (formatted name) is required in every supported generation (RFC 6350
+        // Section 6.2.1; RFC 2426 Section 3.1.1).
+        if vcard.formatted_name().is_none() {
+            return Err(ParseError::at(
+                "Missing required FN (Formatted Name) property (RFC 6350 Section 6.2.1). vCard must include FN property.".to_string(),
+                Position {
+                    line: info.line_number,
+                    column: 1,
+                    offset: info.offset,
+                },
+                info.text.chars().count().max(1),
+                info.text.clone(),
+            ));
         }
 
-        // FN (formatted name) is required (RFC 6350 Section 6.2.1)
-        if vcard.formatted_name().is_none() {
-            return Err(ParseError::new(
-                "Missing required FN (Formatted Name) property (RFC 6350 Section 6.2.1). vCard must include FN property.".to_string()
+        // vCard 3.0 additionally requires N (RFC 2426 Section 3.1.1), where 4.0 made it
+        // optional (RFC 6350 Section 6.2.2).
+        if vcard.vcard_version() == Some(VCardVersion::V3_0) && vcard.name().is_none() {
+            return Err(ParseError::at(
+                "Missing required N (Structured Name) property (RFC 2426 Section 3.1.1). vCard 3.0 must include N property.".to_string(),
+                Position {
+                    line: info.line_number,
+                    column: 1,
+                    offset: info.offset,
+                },
+                info.text.chars().count().max(1),
+                info.text.clone(),
             ));
         }
 
@@ -532,6 +1204,69 @@ impl AdrType {
     }
 }
 
+/// A single `TYPE` parameter value (RFC 6350 Sections 6.3.1 `ADR`, 6.4.1 `TEL`, 6.4.2
+/// `EMAIL`), covering every registered value across those three properties plus an
+/// [`Other`](TypeValue::Other) fallback so [`VCardProperty::types`] never drops data for an
+/// unrecognized or vendor-specific `TYPE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeValue {
+    /// Text telephone
+    Text,
+    /// Voice telephone
+    Voice,
+    /// Fax number
+    Fax,
+    /// Cell phone
+    Cell,
+    /// Video conference
+    Video,
+    /// Pager
+    Pager,
+    /// Text phone (TTY)
+    TextPhone,
+    /// Work address, telephone, or email
+    Work,
+    /// Home address, telephone, or email
+    Home,
+    /// Postal address
+    Postal,
+    /// Parcel delivery address
+    Parcel,
+    /// Domestic address
+    Dom,
+    /// International address
+    Intl,
+    /// Internet email
+    Internet,
+    /// A `TYPE` value not covered by the variants above (e.g. a vendor extension),
+    /// lowercased and kept verbatim rather than dropped.
+    Other(String),
+}
+
+impl TypeValue {
+    /// Parse a raw `TYPE` parameter value, case-insensitively, falling back to
+    /// [`Other`](TypeValue::Other) for anything unrecognized.
+    fn parse(value: &str) -> TypeValue {
+        match value.to_lowercase().as_str() {
+            "text" => TypeValue::Text,
+            "voice" => TypeValue::Voice,
+            "fax" => TypeValue::Fax,
+            "cell" => TypeValue::Cell,
+            "video" => TypeValue::Video,
+            "pager" => TypeValue::Pager,
+            "textphone" => TypeValue::TextPhone,
+            "work" => TypeValue::Work,
+            "home" => TypeValue::Home,
+            "postal" => TypeValue::Postal,
+            "parcel" => TypeValue::Parcel,
+            "dom" => TypeValue::Dom,
+            "intl" => TypeValue::Intl,
+            "internet" => TypeValue::Internet,
+            other => TypeValue::Other(other.to_string()),
+        }
+    }
+}
+
 /// Builder for creating vCard objects with a fluent, type-safe API
 pub struct VCardBuilder {
     vcard: VCardObject,
@@ -587,6 +1322,19 @@ impl VCardBuilder {
         self
     }
 
+    /// Add a telephone number with type parameters and a `PREF` ranking (RFC 6350 Section
+    /// 5.3, 1-100, lower is more preferred) for marking which of several numbers a client
+    /// should try first. See [`VCardObject::telephones_by_preference`].
+    pub fn telephone_with_pref(mut self, number: impl Into<String>, types: Vec<TelType>, pref: u8) -> Self {
+        let mut prop = VCardProperty::new("TEL", number);
+        for tel_type in types {
+            prop.add_parameter("TYPE", tel_type.as_str());
+        }
+        prop.add_parameter("PREF", pref.to_string());
+        self.vcard.add_property(prop);
+        self
+    }
+
     /// Add an email address with type parameters
     pub fn email(mut self, email: impl Into<String>, types: Vec<EmailType>) -> Self {
         let mut prop = VCardProperty::new("EMAIL", email);
@@ -597,6 +1345,20 @@ impl VCardBuilder {
         self
     }
 
+    /// Add an email address with type parameters, rejecting it up front if it isn't a
+    /// well-formed `local-part@domain` address (see [`validation::validate_email`]) instead
+    /// of silently accepting malformed input the way [`email`](Self::email) does.
+    pub fn try_email(mut self, email: impl Into<String>, types: Vec<EmailType>) -> Result<Self, ParseError> {
+        let email = email.into();
+        validation::validate_email(&email).map_err(ParseError::new)?;
+        let mut prop = VCardProperty::new("EMAIL", email);
+        for email_type in types {
+            prop.add_parameter("TYPE", email_type.as_str());
+        }
+        self.vcard.add_property(prop);
+        Ok(self)
+    }
+
     /// Add a delivery address with type parameters
     /// Components: po_box;extended;street;locality;region;postal_code;country
     pub fn address(mut self, address: impl Into<String>, types: Vec<AdrType>) -> Self {
@@ -608,7 +1370,9 @@ impl VCardBuilder {
         self
     }
 
-    /// Add a delivery address with separate components
+    /// Add a delivery address with separate components. If `country` matches a
+    /// recognized ISO 3166-1 alpha-2/alpha-3 code or common name (see [`Country::lookup`]),
+    /// also emits the registered RFC 6350 `CC=` ADR parameter with its alpha-2 code.
     pub fn address_parts(
         mut self,
         po_box: &str,
@@ -628,6 +1392,9 @@ impl VCardBuilder {
         for adr_type in types {
             prop.add_parameter("TYPE", adr_type.as_str());
         }
+        if let Some(recognized) = Country::lookup(country) {
+            prop.add_parameter("CC", recognized.alpha2);
+        }
         self.vcard.add_property(prop);
         self
     }
@@ -686,6 +1453,16 @@ impl VCardBuilder {
         self
     }
 
+    /// Set the URL (URL), rejecting it up front if it doesn't parse as a URI with a scheme
+    /// and authority (see [`validation::validate_url`]) instead of silently accepting
+    /// malformed input the way [`url`](Self::url) does.
+    pub fn try_url(mut self, url: impl Into<String>) -> Result<Self, ParseError> {
+        let url = url.into();
+        validation::validate_url(&url).map_err(ParseError::new)?;
+        self.vcard.add_property(VCardProperty::new("URL", url));
+        Ok(self)
+    }
+
     /// Set the note (NOTE)
     pub fn note(mut self, note: impl Into<String>) -> Self {
         self.vcard.add_property(VCardProperty::new("NOTE", note));
@@ -716,6 +1493,43 @@ impl VCardBuilder {
         self
     }
 
+    /// Add a property with an explicit `group.` prefix (e.g. `item1` for `item1.TEL` /
+    /// `item1.X-ABLabel`), the pattern macOS/iOS Contacts exports use to attach a custom
+    /// label to a sibling property via [`VCardObject::get_properties_in_group`].
+    pub fn custom_property_grouped(
+        mut self,
+        group: impl Into<String>,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        let mut property = VCardProperty::new(name, value);
+        property.group = Some(group.into().to_lowercase());
+        self.vcard.add_property(property);
+        self
+    }
+
+    /// Generate a random (v4) UUID and set it as this vCard's `UID` in the `urn:uuid:`
+    /// form RFC 6350 Section 6.7.6 recommends, giving CardDAV and other sync targets a
+    /// stable globally-unique identifier for this card.
+    pub fn generate_uid(self) -> Self {
+        self.set_uid(Uuid::new_v4())
+    }
+
+    /// Derive a deterministic (v5) UUID from `namespace` and `name` and set it as this
+    /// vCard's `UID`, so the same logical contact (e.g. the same row in an external
+    /// directory) maps to the same `UID` across re-imports instead of a fresh one each time.
+    pub fn uid_from(self, namespace: Uuid, name: &str) -> Self {
+        self.set_uid(Uuid::new_v5(&namespace, name.as_bytes()))
+    }
+
+    /// Set `UID` to `urn:uuid:<uuid>`, replacing rather than duplicating a `UID` already
+    /// set (manually via [`Self::custom_property`] or by an earlier `generate_uid`/
+    /// `uid_from` call).
+    fn set_uid(mut self, uuid: Uuid) -> Self {
+        self.vcard.set_property("UID", format!("urn:uuid:{}", uuid));
+        self
+    }
+
     /// Build and return the vCard object
     pub fn build(self) -> VCardObject {
         self.vcard
@@ -733,15 +1547,233 @@ impl VCardObject {
     pub fn builder() -> VCardBuilder {
         VCardBuilder::new()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Serialize this vCard to RFC 6350 text: `BEGIN:VCARD`/`END:VCARD`, each property
+    /// escaped and folded so no content line exceeds 75 octets.
+    pub fn to_vcard_string(&self) -> String {
+        let mut output = String::from("BEGIN:VCARD\r\n");
 
-    #[test]
-    fn test_parse_simple_vcard() {
-        let vcard_data = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD";
+        for name in ordered_property_names(&self.properties) {
+            for property in &self.properties[name] {
+                output.push_str(&fold_line(&serialize_property(property)));
+                output.push_str("\r\n");
+            }
+        }
+
+        output.push_str("END:VCARD\r\n");
+        output
+    }
+}
+
+impl fmt::Display for VCardObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_vcard_string())
+    }
+}
+
+/// Serialize multiple vCards back into the concatenated text format [`VCardParser::parse`]
+/// accepts, writing out each one's own `BEGIN:VCARD`/`END:VCARD` block in turn so a full
+/// address book round-trips through parse → serialize → parse.
+pub fn vcards_to_string(vcards: &[VCardObject]) -> String {
+    vcards.iter().map(VCardObject::to_vcard_string).collect()
+}
+
+/// Property names in serialization order: `VERSION` and `FN` first (matching how real
+/// vCards are conventionally written), then everything else alphabetically.
+fn ordered_property_names(properties: &HashMap<String, Vec<VCardProperty>>) -> Vec<&String> {
+    let mut names: Vec<&String> = properties.keys().collect();
+    names.sort();
+    names.sort_by_key(|name| match name.as_str() {
+        "VERSION" => 0,
+        "FN" => 1,
+        _ => 2,
+    });
+    names
+}
+
+fn serialize_property(property: &VCardProperty) -> String {
+    let mut line = String::new();
+    if let Some(group) = &property.group {
+        line.push_str(group);
+        line.push('.');
+    }
+    line.push_str(&property.name);
+
+    let mut param_names: Vec<&String> = property.parameters.keys().collect();
+    param_names.sort();
+    for param_name in param_names {
+        let rendered: Vec<String> = property.parameters[param_name]
+            .iter()
+            .map(|value| escape_parameter_value(value))
+            .collect();
+        line.push(';');
+        line.push_str(param_name);
+        line.push('=');
+        line.push_str(&rendered.join(","));
+    }
+
+    line.push(':');
+    if matches!(property.name.as_str(), "N" | "ADR") {
+        // These properties are stored with their `;`/`,` separators already in wire
+        // format (see `unescape_value_preserving_separators`); only the newline escape
+        // still needs to be re-applied.
+        line.push_str(&property.value.replace('\n', "\\n"));
+    } else {
+        line.push_str(&escape_value(&property.value));
+    }
+    line
+}
+
+/// Quote a parameter value if it contains any of the characters RFC 6350 requires
+/// quoting for (`,`, `;`, `:`), after RFC 6868 circumflex-escaping it so a literal `^`,
+/// newline, or `"` in the value survives the round trip.
+fn escape_parameter_value(value: &str) -> String {
+    let escaped = escape_parameter_value_rfc6868(value);
+    if escaped.contains(',') || escaped.contains(';') || escaped.contains(':') {
+        format!("\"{}\"", escaped)
+    } else {
+        escaped
+    }
+}
+
+/// RFC 6868 circumflex-escape a parameter value: `^` -> `^^`, newline -> `^n`, `"` -> `^'`.
+fn escape_parameter_value_rfc6868(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '^' => escaped.push_str("^^"),
+            '\n' => escaped.push_str("^n"),
+            '"' => escaped.push_str("^'"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Inverse of [`escape_parameter_value_rfc6868`]: `^^` -> `^`, `^n` -> newline, `^'` ->
+/// `"`. Per RFC 6868, a `^` followed by anything else is left as-is (not a recognized
+/// escape).
+fn unescape_parameter_value_rfc6868(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '^' {
+            match chars.peek() {
+                Some('^') => {
+                    unescaped.push('^');
+                    chars.next();
+                }
+                Some('n') => {
+                    unescaped.push('\n');
+                    chars.next();
+                }
+                Some('\'') => {
+                    unescaped.push('"');
+                    chars.next();
+                }
+                _ => unescaped.push('^'),
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    unescaped
+}
+
+/// Decode `\n`/`\N` and, unless `preserve_separators`, `\\`, `\;` and `\,` in a single
+/// left-to-right pass (with `preserve_separators`, those three escapes are kept as written
+/// for later component splitting). Chaining whole-string `.replace()` calls instead mis-decodes an
+/// escaped backslash immediately followed by one of those characters — e.g. `a\\nb` (an
+/// escaped `\` followed by a literal `n`) would have its `\n` half-matched by a later
+/// replace and come out as `a` + backslash + an actual newline + `b`, instead of the
+/// correct `a` + backslash + `n` + `b`. Scanning left to right and consuming each escape's
+/// two characters together avoids that.
+fn unescape_value_scanning(value: &str, preserve_separators: bool) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            Some('n') | Some('N') => {
+                result.push('\n');
+                chars.next();
+            }
+            Some('\\') if preserve_separators => {
+                result.push_str("\\\\");
+                chars.next();
+            }
+            Some('\\') => {
+                result.push('\\');
+                chars.next();
+            }
+            Some(';') if !preserve_separators => {
+                result.push(';');
+                chars.next();
+            }
+            Some(',') if !preserve_separators => {
+                result.push(',');
+                chars.next();
+            }
+            // Not a recognized escape (or a `\;`/`\,` left intact for later component
+            // splitting): keep the backslash literal and let the next iteration handle
+            // whatever follows on its own.
+            _ => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+/// Inverse of `VCardParser::unescape_value`: escape `\`, newlines, `,` and `;`.
+fn escape_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+}
+
+/// Fold a content line so no physical line exceeds 75 octets, inserting a CRLF followed
+/// by a single space at a UTF-8-safe boundary (never splitting a multibyte character).
+fn fold_line(line: &str) -> String {
+    const MAX_OCTETS: usize = 75;
+
+    if line.len() <= MAX_OCTETS {
+        return line.to_string();
+    }
+
+    let mut folded = String::new();
+    let mut line_octets = 0;
+    // The first physical line gets the full 75 octets; continuation lines carry a
+    // leading space, so they only have 74 octets left for content.
+    let mut limit = MAX_OCTETS;
+
+    for ch in line.chars() {
+        let ch_len = ch.len_utf8();
+        if line_octets + ch_len > limit {
+            folded.push_str("\r\n ");
+            line_octets = 0;
+            limit = MAX_OCTETS - 1;
+        }
+        folded.push(ch);
+        line_octets += ch_len;
+    }
+
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_simple_vcard() {
+        let vcard_data = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD";
         let mut parser = VCardParser::new();
         let vcards = parser.parse(vcard_data).unwrap();
 
@@ -918,4 +1950,647 @@ mod tests {
         let custom = vcard.get_property("X-CUSTOM").unwrap();
         assert_eq!(custom.value, "Custom Value");
     }
+
+    // Tests for serialization
+
+    #[test]
+    fn test_round_trip_serialize_parse() {
+        let vcard = VCardObject::builder()
+            .version("4.0")
+            .formatted_name("Jane Doe")
+            .telephone("+1-555-555-0100", vec![TelType::Work, TelType::Voice])
+            .email("jane@example.com", vec![EmailType::Home])
+            .build();
+
+        let text = vcard.to_vcard_string();
+        assert!(text.starts_with("BEGIN:VCARD\r\n"));
+        assert!(text.ends_with("END:VCARD\r\n"));
+
+        let mut parser = VCardParser::new();
+        let parsed = parser.parse(&text).unwrap();
+        assert_eq!(parsed.len(), 1);
+
+        let reparsed = &parsed[0];
+        assert_eq!(reparsed.formatted_name(), Some("Jane Doe"));
+
+        let tel = reparsed.get_property("TEL").unwrap();
+        assert_eq!(tel.value, "+1-555-555-0100");
+        let types = tel.get_parameters("TYPE").unwrap();
+        assert!(types.contains(&"work".to_string()));
+        assert!(types.contains(&"voice".to_string()));
+
+        let email = reparsed.get_property("EMAIL").unwrap();
+        assert_eq!(email.value, "jane@example.com");
+    }
+
+    #[test]
+    fn test_round_trip_quoted_parameter_value_with_comma() {
+        let mut vcard = VCardObject::builder().version("4.0").formatted_name("Jane Doe").build();
+        let mut adr = VCardProperty::new("ADR", ";;123 Main St;Springfield;IL;62704;USA");
+        adr.add_parameter("LABEL", "123 Main St, Apt 4");
+        vcard.add_property(adr);
+
+        let text = vcard.to_vcard_string();
+        let mut parser = VCardParser::new();
+        let reparsed = parser.parse(&text).unwrap().remove(0);
+
+        let adr = reparsed.get_property("ADR").unwrap();
+        assert_eq!(adr.get_parameters("LABEL").unwrap(), &vec!["123 Main St, Apt 4".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_error_offset_accounts_for_crlf_terminator() {
+        // "BEGIN:VCARD\r\n" (13 bytes) + "VERSION:4.0\r\n" (13 bytes) puts the malformed "FN"
+        // line's missing-colon error at its end, byte offset 28 - only correct if each
+        // CRLF counts as 2 bytes rather than 1.
+        let text = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+        let error = parser.parse(text).unwrap_err();
+        assert_eq!(error.offset(), 28);
+        assert_eq!(error.line(), 3);
+    }
+
+    #[test]
+    fn test_serialize_escapes_special_characters() {
+        let vcard = VCardObject::builder()
+            .version("4.0")
+            .formatted_name("John Doe")
+            .note("Line one\nLine two; with, special\\chars")
+            .build();
+
+        let text = vcard.to_vcard_string();
+        assert!(text.contains("NOTE:Line one\\nLine two\\; with\\, special\\\\chars"));
+
+        let mut parser = VCardParser::new();
+        let parsed = parser.parse(&text).unwrap();
+        let note = parsed[0].get_property("NOTE").unwrap();
+        assert_eq!(note.value, "Line one\nLine two; with, special\\chars");
+    }
+
+    #[test]
+    fn test_serialize_folds_long_lines_at_75_octets() {
+        let vcard = VCardObject::builder()
+            .version("4.0")
+            .formatted_name("A Very Long Formatted Name That Exceeds Seventy Five Octets In Length Once Serialized")
+            .build();
+
+        let text = vcard.to_vcard_string();
+        for line in text.split("\r\n") {
+            assert!(line.len() <= 75, "line exceeded 75 octets: {:?}", line);
+        }
+
+        let mut parser = VCardParser::new();
+        let parsed = parser.parse(&text).unwrap();
+        assert_eq!(
+            parsed[0].formatted_name(),
+            Some("A Very Long Formatted Name That Exceeds Seventy Five Octets In Length Once Serialized")
+        );
+    }
+
+    #[test]
+    fn test_mutable_accessors() {
+        let mut vcard = VCardObject::builder()
+            .version("4.0")
+            .formatted_name("John Doe")
+            .telephone("+1-555-0100", vec![TelType::Cell])
+            .build();
+
+        vcard.telephones_mut().unwrap()[0].value = "+1-555-0199".to_string();
+        assert_eq!(vcard.telephones().unwrap()[0].value, "+1-555-0199");
+
+        vcard.set_property("FN", "Jane Doe");
+        assert_eq!(vcard.formatted_name(), Some("Jane Doe"));
+
+        vcard.set_property("NICKNAME", "Janey");
+        assert_eq!(vcard.get_property("NICKNAME").unwrap().value, "Janey");
+
+        let removed = vcard.remove_property("TEL").unwrap();
+        assert_eq!(removed.len(), 1);
+        assert!(vcard.telephones().is_none());
+    }
+
+    #[test]
+    fn test_structured_name_splits_components_and_unescapes() {
+        let text = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Doe\\, Jon\\; Q.\r\nN:Doe;Jon\\, Q.;Quinn,Public;Dr.;Jr.\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        let name = vcard.structured_name().unwrap();
+        assert_eq!(name.family, vec!["Doe".to_string()]);
+        assert_eq!(name.given, vec!["Jon, Q.".to_string()]);
+        assert_eq!(name.additional, vec!["Quinn".to_string(), "Public".to_string()]);
+        assert_eq!(name.prefix, vec!["Dr.".to_string()]);
+        assert_eq!(name.suffix, vec!["Jr.".to_string()]);
+    }
+
+    #[test]
+    fn test_as_address_splits_components() {
+        let text = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane Doe\r\nADR:;;123 Main St;Springfield;IL;62704;USA\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        let adr = vcard.addresses().unwrap()[0].as_address().unwrap();
+        assert_eq!(adr.street, vec!["123 Main St".to_string()]);
+        assert_eq!(adr.locality, vec!["Springfield".to_string()]);
+        assert_eq!(adr.region, vec!["IL".to_string()]);
+        assert_eq!(adr.postal_code, vec!["62704".to_string()]);
+        assert_eq!(adr.country, vec!["USA".to_string()]);
+
+        assert!(vcard.get_property("FN").unwrap().as_address().is_none());
+    }
+
+    #[test]
+    fn test_structured_name_and_address_round_trip_through_builder() {
+        let vcard = VCardObject::builder()
+            .version("4.0")
+            .formatted_name("Doe, Jon Q.")
+            .name_parts("Doe", "Jon", "Quinn", "Dr.", "Jr.")
+            .address_parts("", "", "123 Main St", "Springfield", "IL", "62704", "USA", vec![AdrType::Home])
+            .build();
+
+        let text = vcard.to_vcard_string();
+        let mut parser = VCardParser::new();
+        let reparsed = parser.parse(&text).unwrap().remove(0);
+
+        let name = reparsed.structured_name().unwrap();
+        assert_eq!(name.family, vec!["Doe".to_string()]);
+        assert_eq!(name.given, vec!["Jon".to_string()]);
+        assert_eq!(name.additional, vec!["Quinn".to_string()]);
+        assert_eq!(name.prefix, vec!["Dr.".to_string()]);
+        assert_eq!(name.suffix, vec!["Jr.".to_string()]);
+
+        let adr = reparsed.addresses().unwrap()[0].as_address().unwrap();
+        assert_eq!(adr.street, vec!["123 Main St".to_string()]);
+        assert_eq!(adr.locality, vec!["Springfield".to_string()]);
+        assert_eq!(adr.region, vec!["IL".to_string()]);
+        assert_eq!(adr.postal_code, vec!["62704".to_string()]);
+        assert_eq!(adr.country, vec!["USA".to_string()]);
+    }
+
+    #[test]
+    fn test_version_30_is_accepted_and_typed() {
+        let text = "BEGIN:VCARD\r\nVERSION:3.0\r\nFN:Jane Doe\r\nN:Doe;Jane;;;\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        assert_eq!(vcard.vcard_version(), Some(VCardVersion::V3_0));
+    }
+
+    #[test]
+    fn test_version_30_without_n_is_rejected() {
+        let text = "BEGIN:VCARD\r\nVERSION:3.0\r\nFN:Jane Doe\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+
+        let result = parser.parse(text);
+        assert!(result.is_err(), "vCard 3.0 without N should be rejected");
+        assert!(result.unwrap_err().to_string().contains('N'));
+    }
+
+    #[test]
+    fn test_version_21_is_accepted_by_default() {
+        let text = "BEGIN:VCARD\r\nVERSION:2.1\r\nFN:Jane Doe\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+
+        let vcard = parser.parse(text).unwrap().remove(0);
+        assert_eq!(vcard.vcard_version(), Some(VCardVersion::V2_1));
+    }
+
+    #[test]
+    fn test_parse_property_group_prefix() {
+        let text = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane Doe\r\nitem1.TEL:+1-555-0100\r\nitem1.X-ABLabel:Work\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        let tel = vcard.get_property("TEL").unwrap();
+        assert_eq!(tel.group, Some("item1".to_string()));
+
+        let grouped = vcard.get_properties_in_group("item1");
+        assert_eq!(grouped.len(), 2);
+        assert!(grouped.iter().any(|p| p.name == "TEL"));
+        assert!(grouped.iter().any(|p| p.name == "X-ABLABEL"));
+
+        let text_out = vcard.to_vcard_string();
+        assert!(text_out.contains("item1.TEL:+1-555-0100"));
+        assert!(text_out.contains("item1.X-ABLABEL:Work"));
+    }
+
+    #[test]
+    fn test_decode_quoted_printable_note() {
+        let text = "BEGIN:VCARD\r\nVERSION:2.1\r\nFN:Jane Doe\r\nNOTE;ENCODING=QUOTED-PRINTABLE:Caf=C3=A9 & fri=\r\nends\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        let note = vcard.get_property("NOTE").unwrap();
+        assert_eq!(note.value, "Caf=C3=A9 & friends");
+        assert_eq!(
+            note.decoded_value(),
+            Some("Café & friends".as_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn test_decode_base64_photo() {
+        let text = "BEGIN:VCARD\r\nVERSION:3.0\r\nFN:Jane Doe\r\nN:Doe;Jane;;;\r\nPHOTO;ENCODING=b;TYPE=JPEG:aGVsbG8=\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        let photo = vcard.get_property("PHOTO").unwrap();
+        assert_eq!(photo.decoded_value(), Some(b"hello".to_vec()));
+    }
+
+    #[test]
+    fn test_undecodable_property_has_no_decoded_value() {
+        let vcard = VCardObject::builder().formatted_name("Jane Doe").build();
+        assert_eq!(vcard.get_property("FN").unwrap().decoded_value(), None);
+    }
+
+    #[test]
+    fn test_validate_flags_duplicate_single_valued_property() {
+        let text = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane Doe\r\nUID:urn:uuid:1\r\nUID:urn:uuid:2\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        let issues = vcard.validate(ValidationLevel::Lenient);
+        assert!(issues.iter().any(|i| i.property == "UID"));
+    }
+
+    #[test]
+    fn test_validate_strict_flags_bad_type_pref_and_unknown_property() {
+        let text = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane Doe\r\nEMAIL;TYPE=fax;PREF=200:jane@example.com\r\nFOOBAR:whatever\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        let issues = vcard.validate(ValidationLevel::Strict);
+        assert!(issues.iter().any(|i| i.property == "EMAIL" && i.reason.contains("TYPE=fax")));
+        assert!(issues.iter().any(|i| i.property == "EMAIL" && i.reason.contains("PREF=200")));
+        assert!(issues.iter().any(|i| i.property == "FOOBAR"));
+    }
+
+    #[test]
+    fn test_validate_lenient_skips_type_and_unknown_property_checks() {
+        let text = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane Doe\r\nEMAIL;TYPE=fax:jane@example.com\r\nFOOBAR:whatever\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        assert!(vcard.validate(ValidationLevel::Lenient).is_empty());
+    }
+
+    #[test]
+    fn test_validate_accepts_well_formed_card() {
+        let vcard = VCardObject::builder()
+            .version("4.0")
+            .formatted_name("Jane Doe")
+            .email("jane@example.com", vec![EmailType::Work])
+            .build();
+
+        assert!(vcard.validate(ValidationLevel::Strict).is_empty());
+    }
+
+    #[test]
+    fn test_generate_uid_sets_urn_uuid() {
+        let vcard = VCardObject::builder().version("4.0").formatted_name("Jane Doe").generate_uid().build();
+
+        let uid = vcard.get_property("UID").unwrap();
+        assert!(uid.value.starts_with("urn:uuid:"));
+    }
+
+    #[test]
+    fn test_uid_from_is_deterministic() {
+        let build = || {
+            VCardObject::builder()
+                .version("4.0")
+                .formatted_name("Jane Doe")
+                .uid_from(Uuid::NAMESPACE_DNS, "jane.doe@example.com")
+                .build()
+        };
+
+        assert_eq!(build().get_property("UID").unwrap().value, build().get_property("UID").unwrap().value);
+    }
+
+    #[test]
+    fn test_generate_uid_does_not_duplicate_manual_uid() {
+        let vcard = VCardObject::builder()
+            .version("4.0")
+            .formatted_name("Jane Doe")
+            .custom_property("UID", "urn:uuid:11111111-1111-1111-1111-111111111111")
+            .generate_uid()
+            .build();
+
+        assert_eq!(vcard.get_properties("UID").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_parameter_value_rfc6868_round_trip() {
+        let mut vcard = VCardObject::builder().version("4.0").formatted_name("Jane Doe").build();
+        let mut tel = VCardProperty::new("TEL", "+1-555-0100");
+        tel.add_parameter("LABEL", "Caret^Quote\"Newline\nEnd");
+        vcard.add_property(tel);
+
+        let text = vcard.to_vcard_string();
+        assert!(text.contains("^^"), "expected an escaped caret in: {}", text);
+        assert!(text.contains("^n"), "expected an escaped newline in: {}", text);
+        assert!(text.contains("^'"), "expected an escaped quote in: {}", text);
+
+        let mut parser = VCardParser::new();
+        let reparsed = parser.parse(&text).unwrap().remove(0);
+        let label = reparsed.get_property("TEL").unwrap().get_parameter("LABEL").unwrap();
+        assert_eq!(label.as_str(), "Caret^Quote\"Newline\nEnd");
+    }
+
+    #[test]
+    fn test_builder_grouped_property_round_trips_with_label() {
+        let vcard = VCardObject::builder()
+            .version("4.0")
+            .formatted_name("Jane Doe")
+            .custom_property_grouped("item1", "TEL", "+1-555-0100")
+            .custom_property_grouped("item1", "X-ABLabel", "Work")
+            .build();
+
+        let tel = vcard.get_property("TEL").unwrap();
+        assert_eq!(tel.get_property_group(), Some("item1"));
+
+        let text = vcard.to_vcard_string();
+        assert!(text.contains("item1.TEL:+1-555-0100"));
+        assert!(text.contains("item1.X-ABLABEL:Work"));
+
+        let mut parser = VCardParser::new();
+        let reparsed = parser.parse(&text).unwrap().remove(0);
+        let grouped = reparsed.get_properties_in_group("item1");
+        assert_eq!(grouped.len(), 2);
+        assert!(grouped.iter().any(|p| p.name == "TEL" && p.value == "+1-555-0100"));
+        assert!(grouped.iter().any(|p| p.name == "X-ABLABEL" && p.value == "Work"));
+    }
+
+    #[test]
+    fn test_parse_mutt_aliases() {
+        let text = "# personal contacts\nalias jdoe John Doe <john@example.com>\nalias amy Amy Lee amy@example.com\n\nset realname=\"Someone\"\n";
+        let vcards = parse_mutt_aliases(text);
+
+        assert_eq!(vcards.len(), 2);
+        assert_eq!(vcards[0].formatted_name(), Some("John Doe"));
+        assert_eq!(vcards[0].name(), Some("Doe;John;;;"));
+        assert_eq!(vcards[0].emails().unwrap()[0].value, "john@example.com");
+        assert_eq!(vcards[1].formatted_name(), Some("Amy Lee"));
+        assert_eq!(vcards[1].emails().unwrap()[0].value, "amy@example.com");
+    }
+
+    #[test]
+    fn test_mutt_alias_round_trip() {
+        let vcard = VCardObject::builder()
+            .version("4.0")
+            .formatted_name("John Doe")
+            .email("john@example.com", vec![])
+            .build();
+
+        let exported = to_mutt_aliases(&[vcard]);
+        assert_eq!(exported, "alias john John Doe <john@example.com>\n");
+
+        let reimported = parse_mutt_aliases(&exported);
+        assert_eq!(reimported.len(), 1);
+        assert_eq!(reimported[0].formatted_name(), Some("John Doe"));
+        assert_eq!(reimported[0].emails().unwrap()[0].value, "john@example.com");
+    }
+
+    #[test]
+    fn test_country_lookup_normalizes_common_spellings() {
+        assert_eq!(Country::lookup("us").unwrap().alpha2, "US");
+        assert_eq!(Country::lookup("USA").unwrap().alpha2, "US");
+        assert_eq!(Country::lookup("United States").unwrap().alpha3, "USA");
+        assert_eq!(Country::lookup("Narnia"), None);
+    }
+
+    #[test]
+    fn test_address_parts_emits_cc_for_recognized_country() {
+        let vcard = VCardObject::builder()
+            .version("4.0")
+            .formatted_name("Jane Doe")
+            .address_parts("", "", "123 Main St", "Springfield", "IL", "62704", "USA", vec![AdrType::Home])
+            .build();
+
+        let adr = vcard.get_property("ADR").unwrap();
+        assert_eq!(adr.get_parameter("CC"), Some(&"US".to_string()));
+    }
+
+    #[test]
+    fn test_address_parts_skips_cc_for_unrecognized_country() {
+        let vcard = VCardObject::builder()
+            .version("4.0")
+            .formatted_name("Jane Doe")
+            .address_parts("", "", "123 Main St", "Springfield", "IL", "62704", "Narnia", vec![AdrType::Home])
+            .build();
+
+        let adr = vcard.get_property("ADR").unwrap();
+        assert_eq!(adr.get_parameter("CC"), None);
+    }
+
+    #[test]
+    fn test_validate_strict_flags_malformed_email_and_url() {
+        let text = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane Doe\r\nEMAIL:not-an-email\r\nURL:not-a-url\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        let issues = vcard.validate(ValidationLevel::Strict);
+        assert!(issues.iter().any(|i| i.property == "EMAIL" && i.reason.contains("'@'")));
+        assert!(issues.iter().any(|i| i.property == "URL" && i.reason.contains("scheme")));
+    }
+
+    #[test]
+    fn test_validate_strict_accepts_quoted_local_part_and_bracketed_domain() {
+        let text = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane Doe\r\nEMAIL:\"john doe\"@[192.168.1.1]\r\nEND:VCARD\r\n";
+        let mut parser = VCardParser::new();
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        let issues = vcard.validate(ValidationLevel::Strict);
+        assert!(!issues.iter().any(|i| i.property == "EMAIL"));
+    }
+
+    #[test]
+    fn test_try_email_rejects_missing_at_sign() {
+        let result = VCardObject::builder()
+            .formatted_name("Jane Doe")
+            .try_email("not-an-email", vec![EmailType::Work]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_try_email_accepts_well_formed_address() {
+        let vcard = VCardObject::builder()
+            .formatted_name("Jane Doe")
+            .try_email("jane@example.com", vec![EmailType::Work])
+            .unwrap()
+            .build();
+        assert_eq!(vcard.get_property("EMAIL").unwrap().value, "jane@example.com");
+    }
+
+    #[test]
+    fn test_try_url_rejects_missing_authority() {
+        let result = VCardObject::builder().formatted_name("Jane Doe").try_url("mailto:jane@example.com");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_try_url_accepts_well_formed_uri() {
+        let vcard = VCardObject::builder()
+            .formatted_name("Jane Doe")
+            .try_url("https://example.com/jane")
+            .unwrap()
+            .build();
+        assert_eq!(vcard.get_property("URL").unwrap().value, "https://example.com/jane");
+    }
+
+    #[test]
+    fn test_property_pref_and_types() {
+        let vcard = VCardObject::builder()
+            .formatted_name("Jane Doe")
+            .telephone_with_pref("+1-555-555-1234", vec![TelType::Work, TelType::Voice], 1)
+            .build();
+
+        let tel = vcard.get_property("TEL").unwrap();
+        assert_eq!(tel.pref(), Some(1));
+        assert_eq!(tel.types(), vec![TypeValue::Work, TypeValue::Voice]);
+    }
+
+    #[test]
+    fn test_property_types_keeps_unrecognized_value() {
+        let mut tel = VCardProperty::new("TEL", "+1-555-555-1234");
+        tel.add_parameter("TYPE", "smartphone");
+        assert_eq!(tel.types(), vec![TypeValue::Other("smartphone".to_string())]);
+    }
+
+    #[test]
+    fn test_property_pref_is_none_without_parameter() {
+        let vcard = VCardObject::builder()
+            .formatted_name("Jane Doe")
+            .telephone("+1-555-555-1234", vec![TelType::Home])
+            .build();
+        assert_eq!(vcard.get_property("TEL").unwrap().pref(), None);
+    }
+
+    #[test]
+    fn test_telephones_by_preference_ranks_lowest_pref_first() {
+        let vcard = VCardObject::builder()
+            .formatted_name("Jane Doe")
+            .telephone("555-555-0000", vec![TelType::Fax])
+            .telephone_with_pref("+1-555-555-1234", vec![TelType::Cell], 2)
+            .telephone_with_pref("+1-555-555-0100", vec![TelType::Work], 1)
+            .build();
+
+        let ranked = vcard.telephones_by_preference();
+        let values: Vec<&str> = ranked.iter().map(|tel| tel.value.as_str()).collect();
+        assert_eq!(values, vec!["+1-555-555-0100", "+1-555-555-1234", "555-555-0000"]);
+    }
+
+    #[test]
+    fn test_telephones_by_preference_empty_without_tel() {
+        let vcard = VCardObject::builder().formatted_name("Jane Doe").build();
+        assert_eq!(vcard.telephones_by_preference(), Vec::<&VCardProperty>::new());
+    }
+
+    #[test]
+    fn test_to_jcard_serializes_simple_property() {
+        let vcard = VCardObject::builder().formatted_name("Jane Doe").build();
+        assert_eq!(vcard.to_jcard(), serde_json::json!(["vcard", [["fn", {}, "text", "Jane Doe"]]]));
+    }
+
+    #[test]
+    fn test_jcard_round_trip_tel_with_type_and_uri_value_type() {
+        let vcard = VCardObject::builder()
+            .formatted_name("Jane Doe")
+            .telephone("tel:+1-555-1234", vec![TelType::Home, TelType::Voice])
+            .build();
+
+        let jcard = vcard.to_jcard();
+        let tel = jcard[1].as_array().unwrap().iter().find(|p| p[0] == "tel").unwrap();
+        assert_eq!(tel[1], serde_json::json!({"type": ["home", "voice"]}));
+        assert_eq!(tel[2], "uri");
+        assert_eq!(tel[3], "tel:+1-555-1234");
+
+        let mut parser = VCardParser::new();
+        let reparsed = parser.parse_jcard(&jcard.to_string()).unwrap();
+        let tel = reparsed.get_property("TEL").unwrap();
+        assert_eq!(tel.value, "tel:+1-555-1234");
+        assert_eq!(tel.get_parameters("TYPE").unwrap(), &vec!["home".to_string(), "voice".to_string()]);
+    }
+
+    #[test]
+    fn test_jcard_round_trip_structured_n() {
+        let mut parser = VCardParser::new();
+        let text = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Doe Jane\r\nN:Doe;Jane;Q;Dr.;Jr.\r\nEND:VCARD\r\n";
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        let jcard = vcard.to_jcard();
+        let n = jcard[1].as_array().unwrap().iter().find(|p| p[0] == "n").unwrap();
+        assert_eq!(n[3], serde_json::json!(["Doe", "Jane", "Q", "Dr.", "Jr."]));
+
+        let reparsed = parser.parse_jcard(&jcard.to_string()).unwrap();
+        assert_eq!(reparsed.get_property("N").unwrap().value, "Doe;Jane;Q;Dr.;Jr.");
+    }
+
+    #[test]
+    fn test_jcard_round_trip_preserves_literal_comma_in_structured_component() {
+        let mut parser = VCardParser::new();
+        let text = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane Doe\r\nN:Smith\\, Jr;Jane;;;\r\nEND:VCARD\r\n";
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        let jcard = vcard.to_jcard();
+        let reparsed = parser.parse_jcard(&jcard.to_string()).unwrap();
+
+        let name = reparsed.get_property("N").unwrap().as_structured_name().unwrap();
+        assert_eq!(name.family, vec!["Smith, Jr".to_string()]);
+    }
+
+    #[test]
+    fn test_jcard_round_trip_preserves_group_param() {
+        let vcard = VCardObject::builder()
+            .formatted_name("Jane Doe")
+            .custom_property_grouped("item1", "X-ABLabel", "Mobile")
+            .build();
+
+        let jcard = vcard.to_jcard();
+        let labeled = jcard[1].as_array().unwrap().iter().find(|p| p[0] == "x-ablabel").unwrap();
+        assert_eq!(labeled[1], serde_json::json!({"group": "item1"}));
+
+        let mut parser = VCardParser::new();
+        let reparsed = parser.parse_jcard(&jcard.to_string()).unwrap();
+        let labeled = reparsed.get_property("X-ABLABEL").unwrap();
+        assert_eq!(labeled.get_property_group(), Some("item1"));
+    }
+
+    #[test]
+    fn test_jcard_round_trip_preserves_scalar_value_unescaped() {
+        let mut parser = VCardParser::new();
+        let text = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Doe\\, Jane\r\nEND:VCARD\r\n";
+        let vcard = parser.parse(text).unwrap().remove(0);
+        assert_eq!(vcard.get_property("FN").unwrap().value, "Doe, Jane");
+
+        let jcard = vcard.to_jcard();
+        let fn_prop = jcard[1].as_array().unwrap().iter().find(|p| p[0] == "fn").unwrap();
+        assert_eq!(fn_prop[3], "Doe, Jane");
+
+        let reparsed = parser.parse_jcard(&jcard.to_string()).unwrap();
+        assert_eq!(reparsed.get_property("FN").unwrap().value, "Doe, Jane");
+    }
+
+    #[test]
+    fn test_jcard_preserves_parameter_value_case() {
+        let vcard = VCardObject::builder().formatted_name("Jane Doe").build();
+        let mut adr = VCardProperty::new("ADR", ";;123 Main St;Springfield;;;");
+        adr.add_parameter("LABEL", "123 Main St, Springfield");
+        let mut vcard = vcard;
+        vcard.add_property(adr);
+
+        let jcard = vcard.to_jcard();
+        let adr = jcard[1].as_array().unwrap().iter().find(|p| p[0] == "adr").unwrap();
+        assert_eq!(adr[1], serde_json::json!({"label": "123 Main St, Springfield"}));
+    }
+
+    #[test]
+    fn test_unescape_handles_escaped_backslash_before_separator_char() {
+        let mut parser = VCardParser::new();
+        let text = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane Doe\r\nN:a\\\\nb;;;;\r\nEND:VCARD\r\n";
+        let vcard = parser.parse(text).unwrap().remove(0);
+
+        let name = vcard.get_property("N").unwrap().as_structured_name().unwrap();
+        assert_eq!(name.family, vec!["a\\nb".to_string()]);
+    }
 }
@@ -0,0 +1,82 @@
+//! Import/export [mutt](http://www.mutt.org/) alias files as an alternate contact source.
+//!
+//! A mutt alias file has one contact per line:
+//! ```text
+//! alias jdoe John Doe <john@example.com>
+//! # a comment
+//! alias amy Amy Lee amy@example.com
+//! ```
+//! This gives users a bridge between a plain-text address book and vCard without a full
+//! mail client, mirroring how some MUAs treat an alias file as a read-only contact backend.
+
+use crate::{VCardObject, VCardProperty};
+
+/// Parse a mutt alias file into one [`VCardObject`] per `alias` line, mapping the full
+/// name to `FN`/`N` and the address to `EMAIL`. Blank lines and `#`-comments are skipped;
+/// a line that isn't a recognized `alias` line is skipped too, since alias files may also
+/// contain `set`/`unalias`/other mutt directives we don't model.
+pub fn parse_mutt_aliases(text: &str) -> Vec<VCardObject> {
+    text.lines().filter_map(parse_alias_line).collect()
+}
+
+fn parse_alias_line(line: &str) -> Option<VCardObject> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let rest = line.strip_prefix("alias ")?.trim();
+    let (_key, rest) = rest.split_once(char::is_whitespace)?;
+    let rest = rest.trim();
+
+    // The address is the last whitespace-separated token, optionally `<...>`-wrapped; the
+    // full name is everything before it.
+    let (name, address) = rest.rsplit_once(char::is_whitespace)?;
+    let address = address.trim().trim_start_matches('<').trim_end_matches('>');
+    let name = name.trim();
+
+    if name.is_empty() || address.is_empty() {
+        return None;
+    }
+
+    let mut vcard = VCardObject::new();
+    vcard.add_property(VCardProperty::new("VERSION", "4.0"));
+    vcard.add_property(VCardProperty::new("FN", name));
+    vcard.add_property(VCardProperty::new("N", name_to_structured_n(name)));
+    vcard.add_property(VCardProperty::new("EMAIL", address));
+
+    Some(vcard)
+}
+
+/// Turn a plain `"Given... Family"` display name into an RFC 6350 `N` value
+/// (`Family;Given;;;`), splitting on the last space since mutt aliases don't carry
+/// structured name components.
+fn name_to_structured_n(name: &str) -> String {
+    match name.rsplit_once(' ') {
+        Some((given, family)) => format!("{};{};;;", family, given),
+        None => format!("{};;;;", name),
+    }
+}
+
+/// Export a set of vCards back to mutt alias lines, one per card with an `EMAIL`. The
+/// alias key is the email's local part (lowercased), matching the common mutt convention
+/// of keying aliases off the address itself.
+pub fn to_mutt_aliases(vcards: &[VCardObject]) -> String {
+    let mut output = String::new();
+    for vcard in vcards {
+        let Some(email) = vcard.emails().and_then(|emails| emails.first()) else {
+            continue;
+        };
+        let name = vcard.formatted_name().unwrap_or(&email.value);
+        let key = email.value.split('@').next().unwrap_or(&email.value).to_lowercase();
+
+        output.push_str("alias ");
+        output.push_str(&key);
+        output.push(' ');
+        output.push_str(name);
+        output.push_str(" <");
+        output.push_str(&email.value);
+        output.push_str(">\n");
+    }
+    output
+}
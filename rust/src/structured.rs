@@ -0,0 +1,162 @@
+//! Typed, escape-aware views over the `;`-structured `N` and `ADR` properties, so callers
+//! don't have to re-implement RFC 6350 component splitting (and get it subtly wrong around
+//! escaped `\;`/`\,` separators) every time they want a person's given name or a street
+//! address.
+
+use crate::{VCardObject, VCardProperty};
+
+/// The decoded components of an `N` (structured name) property.
+///
+/// Each field holds one value per comma-separated alternative in that component (e.g. a
+/// double-barrelled given name), in source order; a component with a single value is just
+/// a one-element vector.
+///
+/// Note: a later request for a builder round-trip test asked for this type by the names
+/// `prefixes`/`suffixes`; this is that same type (`prefix`/`suffix`) reused rather than a
+/// second, parallel one, since the crate already had it from an earlier request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StructuredName {
+    pub family: Vec<String>,
+    pub given: Vec<String>,
+    pub additional: Vec<String>,
+    pub prefix: Vec<String>,
+    pub suffix: Vec<String>,
+}
+
+/// The decoded components of an `ADR` (address) property.
+///
+/// Each field holds one value per comma-separated alternative in that component, in
+/// source order; a component with a single value is just a one-element vector.
+///
+/// Note: a later request for a builder round-trip test asked for this type under the name
+/// `StructuredAddress` with a `po_box` field; this is that same type (`Address`/`pobox`)
+/// reused rather than a second, parallel one, since the crate already had it from an
+/// earlier request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Address {
+    pub pobox: Vec<String>,
+    pub extended: Vec<String>,
+    pub street: Vec<String>,
+    pub locality: Vec<String>,
+    pub region: Vec<String>,
+    pub postal_code: Vec<String>,
+    pub country: Vec<String>,
+}
+
+impl VCardObject {
+    /// Decode the `N` property, if present, into a [`StructuredName`].
+    pub fn structured_name(&self) -> Option<StructuredName> {
+        self.get_property("N").and_then(|property| property.as_structured_name())
+    }
+}
+
+impl VCardProperty {
+    /// Decode this property's `;`-separated value into a [`StructuredName`].
+    ///
+    /// Returns `None` if this isn't an `N` property.
+    pub fn as_structured_name(&self) -> Option<StructuredName> {
+        if self.name != "N" {
+            return None;
+        }
+        let mut components = split_components(&self.value).into_iter();
+        Some(StructuredName {
+            family: components.next().unwrap_or_default(),
+            given: components.next().unwrap_or_default(),
+            additional: components.next().unwrap_or_default(),
+            prefix: components.next().unwrap_or_default(),
+            suffix: components.next().unwrap_or_default(),
+        })
+    }
+
+    /// Decode this property's `;`-separated value into an [`Address`].
+    ///
+    /// Returns `None` if this isn't an `ADR` property.
+    pub fn as_address(&self) -> Option<Address> {
+        if self.name != "ADR" {
+            return None;
+        }
+        let mut components = split_components(&self.value).into_iter();
+        Some(Address {
+            pobox: components.next().unwrap_or_default(),
+            extended: components.next().unwrap_or_default(),
+            street: components.next().unwrap_or_default(),
+            locality: components.next().unwrap_or_default(),
+            region: components.next().unwrap_or_default(),
+            postal_code: components.next().unwrap_or_default(),
+            country: components.next().unwrap_or_default(),
+        })
+    }
+}
+
+/// Split a structured value on `;` into its components, then each component on `,` into
+/// its multi-values, unescaping each final value.
+pub(crate) fn split_components(value: &str) -> Vec<Vec<String>> {
+    split_unescaped(value, ';')
+        .into_iter()
+        .map(|component| {
+            split_unescaped(&component, ',')
+                .into_iter()
+                .map(|v| unescape_component(&v))
+                .collect()
+        })
+        .collect()
+}
+
+/// Split `text` on `separator`, treating a `\`-prefixed separator (or any other
+/// `\`-prefixed character) as a literal rather than a boundary. The escaping is left
+/// intact in the returned pieces; call [`unescape_component`] on each one afterwards.
+fn split_unescaped(text: &str, separator: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            current.push('\\');
+            if let Some(escaped) = chars.next() {
+                current.push(escaped);
+            }
+        } else if c == separator {
+            parts.push(std::mem::take(&mut current));
+        } else {
+            current.push(c);
+        }
+    }
+    parts.push(current);
+
+    parts
+}
+
+/// Unescape the `\;`, `\,` and `\\` left behind by [`split_unescaped`] (`\n`/`\N` were
+/// already unescaped for the whole value before splitting), scanning left to right so an
+/// escaped backslash isn't itself mistaken for the start of the next escape (e.g. `\\;` is
+/// an escaped `\` followed by a literal `;`, not an escaped `;` preceded by a bare `\`).
+fn unescape_component(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            Some(';') => {
+                result.push(';');
+                chars.next();
+            }
+            Some(',') => {
+                result.push(',');
+                chars.next();
+            }
+            Some('\\') => {
+                result.push('\\');
+                chars.next();
+            }
+            _ => result.push('\\'),
+        }
+    }
+
+    result
+}
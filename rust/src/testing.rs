@@ -0,0 +1,191 @@
+//! Data-driven harness for the negative-test corpus, inspired by `ui_test`/`compiletest_rs`.
+//!
+//! [`run_negative_testcases`] walks a directory of `.vcf` fixtures, parses each one, and
+//! compares the rendered [`crate::ParseError`] against an expectation taken either from a
+//! companion `<name>.stderr` file or from `#~ ERROR: <substring>` annotation comments at
+//! the top of the `.vcf` file itself. Set the `BLESS=1` environment variable to rewrite
+//! `.stderr` fixtures from the actual output instead of failing on a mismatch, so
+//! maintainers can regenerate them after changing error wording.
+
+use crate::VCardParser;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Outcome of running a single fixture through [`run_negative_testcases`].
+#[derive(Debug, Clone)]
+pub struct TestCaseResult {
+    /// The fixture's file stem (e.g. `missing_version`).
+    pub name: String,
+    /// Whether the actual error matched the expectation (or was freshly blessed).
+    pub passed: bool,
+    /// Human-readable detail: the actual error on success, or a diff-style explanation
+    /// of the mismatch on failure.
+    pub message: String,
+}
+
+/// How a mismatched expectation should be handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputConflictHandling {
+    /// Fail the test case on a mismatch.
+    Error,
+    /// Rewrite the `.stderr` fixture with the actual output instead of failing.
+    Bless,
+}
+
+impl OutputConflictHandling {
+    /// Read from the `BLESS` environment variable: `BLESS=1` selects [`Self::Bless`].
+    pub fn from_env() -> Self {
+        match env::var("BLESS") {
+            Ok(value) if value == "1" => OutputConflictHandling::Bless,
+            _ => OutputConflictHandling::Error,
+        }
+    }
+}
+
+/// Run every `.vcf` fixture in `dir` against its expectation and return one result per file.
+pub fn run_negative_testcases(dir: impl AsRef<Path>) -> Vec<TestCaseResult> {
+    let handling = OutputConflictHandling::from_env();
+
+    let mut vcf_files: Vec<PathBuf> = fs::read_dir(dir.as_ref())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("vcf"))
+        .collect();
+    vcf_files.sort();
+
+    vcf_files
+        .into_iter()
+        .map(|vcf_path| run_one_testcase(&vcf_path, handling))
+        .collect()
+}
+
+fn run_one_testcase(vcf_path: &Path, handling: OutputConflictHandling) -> TestCaseResult {
+    let name = vcf_path.file_stem().unwrap().to_string_lossy().into_owned();
+
+    let content = match fs::read_to_string(vcf_path) {
+        Ok(content) => content,
+        Err(e) => {
+            return TestCaseResult {
+                name,
+                passed: false,
+                message: format!("failed to read {}: {}", vcf_path.display(), e),
+            };
+        }
+    };
+
+    // Blank out the leading `#` header before parsing so the parser sees only the vCard;
+    // blank lines keep the reported line numbers matching the fixture file.
+    let header_lines = content.lines().take_while(|line| line.trim_start().starts_with('#')).count();
+    let vcard_text = content
+        .lines()
+        .enumerate()
+        .map(|(index, line)| if index < header_lines { "" } else { line })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut parser = VCardParser::new();
+    let actual = match parser.parse(&vcard_text) {
+        Ok(_) => "<no error: parse unexpectedly succeeded>".to_string(),
+        Err(error) => error.to_string(),
+    };
+
+    let annotations = extract_annotations(&content);
+    if !annotations.is_empty() {
+        return check_against_annotations(name, &actual, &annotations);
+    }
+
+    check_against_stderr_file(name, &actual, &vcf_path.with_extension("stderr"), handling)
+}
+
+fn check_against_annotations(name: String, actual: &str, annotations: &[String]) -> TestCaseResult {
+    for annotation in annotations {
+        if !actual.contains(annotation.as_str()) {
+            return TestCaseResult {
+                name,
+                passed: false,
+                message: format!("expected error to contain {:?}, got: {}", annotation, actual),
+            };
+        }
+    }
+
+    TestCaseResult {
+        name,
+        passed: true,
+        message: actual.to_string(),
+    }
+}
+
+fn check_against_stderr_file(
+    name: String,
+    actual: &str,
+    expected_path: &Path,
+    handling: OutputConflictHandling,
+) -> TestCaseResult {
+    if !expected_path.exists() {
+        return match handling {
+            OutputConflictHandling::Bless => {
+                bless(expected_path, actual);
+                TestCaseResult {
+                    name,
+                    passed: true,
+                    message: format!("blessed {}", expected_path.display()),
+                }
+            }
+            OutputConflictHandling::Error => TestCaseResult {
+                name,
+                passed: false,
+                message: format!(
+                    "no expectation found (no {} and no `#~ ERROR:` annotations); rerun with BLESS=1 to create one",
+                    expected_path.display()
+                ),
+            },
+        };
+    }
+
+    let expected = fs::read_to_string(expected_path).unwrap_or_default();
+    if expected.trim() == actual.trim() {
+        return TestCaseResult {
+            name,
+            passed: true,
+            message: actual.to_string(),
+        };
+    }
+
+    match handling {
+        OutputConflictHandling::Bless => {
+            bless(expected_path, actual);
+            TestCaseResult {
+                name,
+                passed: true,
+                message: format!("blessed {}", expected_path.display()),
+            }
+        }
+        OutputConflictHandling::Error => TestCaseResult {
+            name,
+            passed: false,
+            message: format!(
+                "mismatch against {}\nexpected:\n{}\nactual:\n{}",
+                expected_path.display(),
+                expected,
+                actual
+            ),
+        },
+    }
+}
+
+fn bless(expected_path: &Path, actual: &str) {
+    let _ = fs::write(expected_path, actual);
+}
+
+/// Pull `#~ ERROR: <substring>` annotation comments out of a fixture's leading `#` header.
+fn extract_annotations(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .take_while(|line| line.trim_start().starts_with('#'))
+        .filter_map(|line| line.trim_start().strip_prefix("#~ ERROR:"))
+        .map(|annotation| annotation.trim().to_string())
+        .collect()
+}
@@ -0,0 +1,198 @@
+//! An opt-in semantic validator for an already-parsed [`VCardObject`].
+//!
+//! `VCardParser` only rejects structurally broken input (missing `VERSION`/`FN`,
+//! unparseable lines, ...). It doesn't catch a card that parses fine but is semantically
+//! off — a `TYPE=fax` on an `EMAIL`, a second `UID`, a `PREF=200`, an unregistered property
+//! name. [`VCardObject::validate`] flags those so an importer can warn-but-accept instead
+//! of the all-or-nothing `Result` that `VCardParser::parse` forces.
+
+use crate::VCardObject;
+
+/// Registered (non-`X-`) vCard 4.0 property names (RFC 6350 Section 6).
+const KNOWN_PROPERTIES: &[&str] = &[
+    "BEGIN", "END", "SOURCE", "KIND", "FN", "N", "NICKNAME", "PHOTO", "BDAY", "ANNIVERSARY",
+    "GENDER", "ADR", "TEL", "EMAIL", "IMPP", "LANG", "TZ", "GEO", "TITLE", "ROLE", "LOGO", "ORG",
+    "MEMBER", "RELATED", "CATEGORIES", "NOTE", "PRODID", "REV", "SOUND", "UID", "CLIENTPIDMAP",
+    "URL", "KEY", "FBURL", "CALADRURI", "CALURI", "VERSION", "XML",
+];
+
+/// Properties RFC 6350 Section 6.1.3 restricts to cardinality `1` (at most once per vCard).
+const SINGLE_VALUED_PROPERTIES: &[&str] = &["N", "BDAY", "GENDER", "PRODID", "REV", "UID"];
+
+/// The registered `TYPE` values for properties whose grammar constrains them (RFC 6350
+/// Sections 6.3.1 `ADR`, 6.4.1 `TEL`, 6.4.2 `EMAIL`). Properties not listed here have no
+/// `TYPE` check applied.
+fn allowed_types(property_name: &str) -> Option<&'static [&'static str]> {
+    match property_name {
+        "TEL" => Some(&["text", "voice", "fax", "cell", "video", "pager", "textphone", "work", "home"]),
+        "EMAIL" => Some(&["work", "home", "internet"]),
+        "ADR" => Some(&["work", "home", "postal", "parcel", "dom", "intl"]),
+        _ => None,
+    }
+}
+
+/// How thorough [`VCardObject::validate`] should be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationLevel {
+    /// Only the cardinality check: a property RFC 6350 caps at one instance appearing more
+    /// than once. This is the closest thing to a structural error `validate` can catch.
+    Lenient,
+    /// Every rule: cardinality, disallowed `TYPE` values, out-of-range `PREF`,
+    /// unregistered (non-`X-`) property names, and malformed `EMAIL`/`URL` values.
+    Strict,
+}
+
+/// A single semantic rule violation found by [`VCardObject::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// The property the issue was found on (e.g. `"TEL"`, `"UID"`).
+    pub property: String,
+    /// A human-readable explanation of what's wrong.
+    pub reason: String,
+}
+
+impl ValidationIssue {
+    fn new(property: impl Into<String>, reason: impl Into<String>) -> Self {
+        ValidationIssue {
+            property: property.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl VCardObject {
+    /// Flag semantically illegal properties that parsed successfully but violate an RFC
+    /// 6350 rule `VCardParser` doesn't enforce. See [`ValidationLevel`] for what each level
+    /// checks.
+    pub fn validate(&self, level: ValidationLevel) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        for name in SINGLE_VALUED_PROPERTIES {
+            if let Some(properties) = self.get_properties(name) {
+                if properties.len() > 1 {
+                    issues.push(ValidationIssue::new(
+                        *name,
+                        format!(
+                            "{} must appear at most once (RFC 6350 Section 6.1.3), but appears {} times",
+                            name,
+                            properties.len()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if level == ValidationLevel::Lenient {
+            return issues;
+        }
+
+        for (name, properties) in &self.properties {
+            for property in properties {
+                if let Some(allowed) = allowed_types(name) {
+                    for ty in property.get_parameters("TYPE").into_iter().flatten() {
+                        if !allowed.contains(&ty.to_lowercase().as_str()) {
+                            issues.push(ValidationIssue::new(
+                                name.clone(),
+                                format!("TYPE={} is not a registered value for {}", ty, name),
+                            ));
+                        }
+                    }
+                }
+
+                if let Some(pref) = property.get_parameter("PREF") {
+                    let in_range = pref.parse::<u32>().is_ok_and(|n| (1..=100).contains(&n));
+                    if !in_range {
+                        issues.push(ValidationIssue::new(
+                            name.clone(),
+                            format!("PREF={} must be an integer from 1 to 100 (RFC 6350 Section 5.3)", pref),
+                        ));
+                    }
+                }
+
+                if !name.starts_with("X-") && !KNOWN_PROPERTIES.contains(&name.as_str()) {
+                    issues.push(ValidationIssue::new(
+                        name.clone(),
+                        format!("{} is not a registered vCard property (prefix custom properties with X-)", name),
+                    ));
+                }
+
+                if name == "EMAIL" {
+                    if let Err(reason) = validate_email(&property.value) {
+                        issues.push(ValidationIssue::new(name.clone(), reason));
+                    }
+                }
+
+                if name == "URL" {
+                    if let Err(reason) = validate_url(&property.value) {
+                        issues.push(ValidationIssue::new(name.clone(), reason));
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// Check that `value` has the `local-part@domain` shape RFC 5321 Section 4.1.2 requires,
+/// without validating the finer-grained grammar of either half. The local part may be
+/// `"quoted"` (allowing `@` and whitespace inside the quotes); the domain may be a
+/// `[bracketed literal]` (e.g. an IP address) instead of a hostname.
+pub(crate) fn validate_email(value: &str) -> Result<(), String> {
+    let (local, domain) = split_email(value).ok_or_else(|| {
+        format!("\"{}\" is missing the '@' separating the local part from the domain", value)
+    })?;
+
+    if local.is_empty() {
+        return Err(format!("\"{}\" has an empty local part before '@'", value));
+    }
+    if domain.is_empty() {
+        return Err(format!("\"{}\" has an empty domain after '@'", value));
+    }
+    if domain.starts_with('[') && (!domain.ends_with(']') || domain.len() <= 2) {
+        return Err(format!("\"{}\" has an unterminated domain literal", value));
+    }
+
+    Ok(())
+}
+
+/// Split `value` into `(local, domain)` around the separating `@`, honoring a `"quoted"`
+/// local part so an `@` inside the quotes isn't mistaken for the separator.
+fn split_email(value: &str) -> Option<(&str, &str)> {
+    if let Some(rest) = value.strip_prefix('"') {
+        let close = rest.find('"')?;
+        let closing_quote = 1 + close;
+        let domain = value.get(closing_quote + 1..)?.strip_prefix('@')?;
+        return Some((&value[..=closing_quote], domain));
+    }
+
+    let at = value.find('@')?;
+    Some((&value[..at], &value[at + 1..]))
+}
+
+/// Check that `value` parses as an RFC 3986 generic URI with a `scheme:` and a `//authority`
+/// — the shape RFC 6350's `URL` property (Section 6.7.8) expects.
+pub(crate) fn validate_url(value: &str) -> Result<(), String> {
+    let (scheme, rest) = value
+        .split_once(':')
+        .ok_or_else(|| format!("\"{}\" has no URI scheme", value))?;
+
+    let scheme_is_valid = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !scheme_is_valid {
+        return Err(format!(
+            "\"{}\" has an invalid URI scheme (must start with a letter and contain only letters, digits, '+', '-', '.')",
+            value
+        ));
+    }
+
+    let authority = rest
+        .strip_prefix("//")
+        .ok_or_else(|| format!("\"{}\" is missing the '//' authority component", value))?;
+    let authority = &authority[..authority.find(['/', '?', '#']).unwrap_or(authority.len())];
+    if authority.is_empty() {
+        return Err(format!("\"{}\" has an empty authority component", value));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,229 @@
+//! A first-class `major.minor` vCard version, plus a small `semver`-`VersionReq`-style
+//! requirement language (`>=4.0`, `3.0 || 4.0`, ...) so [`crate::VCardParser`] can compare
+//! and validate `VERSION:` values instead of matching them against hardcoded strings.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsed `VERSION:` value, e.g. `4.0` or `2.1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl Version {
+    /// Create a version directly from its components.
+    pub fn new(major: u32, minor: u32) -> Self {
+        Version { major, minor }
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.major.cmp(&other.major).then(self.minor.cmp(&other.minor))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// A `VERSION:` value that didn't parse as `major[.minor]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionParseError(String);
+
+impl fmt::Display for VersionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid vCard version: {}", self.0)
+    }
+}
+
+impl std::error::Error for VersionParseError {}
+
+impl FromStr for Version {
+    type Err = VersionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let mut parts = trimmed.splitn(2, '.');
+        let major = parts
+            .next()
+            .and_then(|p| p.parse::<u32>().ok())
+            .ok_or_else(|| VersionParseError(trimmed.to_string()))?;
+        let minor = match parts.next() {
+            Some(minor_str) => minor_str
+                .parse::<u32>()
+                .map_err(|_| VersionParseError(trimmed.to_string()))?,
+            None => 0,
+        };
+
+        Ok(Version { major, minor })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum VersionComparator {
+    Exact(Version),
+    AtLeast(Version),
+    AtMost(Version),
+}
+
+impl VersionComparator {
+    fn matches(&self, version: Version) -> bool {
+        match self {
+            VersionComparator::Exact(req) => version == *req,
+            VersionComparator::AtLeast(req) => version >= *req,
+            VersionComparator::AtMost(req) => version <= *req,
+        }
+    }
+}
+
+impl fmt::Display for VersionComparator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VersionComparator::Exact(v) => write!(f, "{}", v),
+            VersionComparator::AtLeast(v) => write!(f, ">={}", v),
+            VersionComparator::AtMost(v) => write!(f, "<={}", v),
+        }
+    }
+}
+
+/// The vCard spec generations this crate knows how to parse.
+///
+/// Derived from a parsed [`Version`] via [`VCardVersion::from_version`]; unlike `Version`
+/// itself, this only recognizes the `major.minor` pairs that are an actual published vCard
+/// revision, so callers can `match` on it instead of comparing major/minor numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VCardVersion {
+    /// vCard 2.1 (the original, pre-RFC format).
+    V2_1,
+    /// vCard 3.0 (RFC 2426).
+    V3_0,
+    /// vCard 4.0 (RFC 6350).
+    V4_0,
+}
+
+impl VCardVersion {
+    /// Classify a parsed `VERSION:` value into its spec generation, if recognized.
+    pub fn from_version(version: Version) -> Option<Self> {
+        match (version.major, version.minor) {
+            (2, 1) => Some(VCardVersion::V2_1),
+            (3, 0) => Some(VCardVersion::V3_0),
+            (4, 0) => Some(VCardVersion::V4_0),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for VCardVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            VCardVersion::V2_1 => "2.1",
+            VCardVersion::V3_0 => "3.0",
+            VCardVersion::V4_0 => "4.0",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A set of acceptable vCard versions, e.g. `4.0`, `>=4.0`, or `3.0 || 4.0`.
+///
+/// Defaults to `2.1 || 3.0 || 4.0`, since real-world address books (Google, Apple,
+/// Outlook) overwhelmingly export 3.0 or 2.1 rather than the current 4.0; pass a narrower
+/// requirement to [`crate::VCardParser::accept_versions`] (e.g.
+/// `VersionReq::exact(Version::new(4, 0))`) to reject legacy documents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionReq {
+    comparators: Vec<VersionComparator>,
+}
+
+impl VersionReq {
+    /// A requirement that accepts exactly one version.
+    pub fn exact(version: Version) -> Self {
+        VersionReq {
+            comparators: vec![VersionComparator::Exact(version)],
+        }
+    }
+
+    /// A requirement that accepts `version` or anything newer.
+    pub fn at_least(version: Version) -> Self {
+        VersionReq {
+            comparators: vec![VersionComparator::AtLeast(version)],
+        }
+    }
+
+    /// Does `version` satisfy this requirement?
+    pub fn matches(&self, version: Version) -> bool {
+        self.comparators.iter().any(|c| c.matches(version))
+    }
+}
+
+impl Default for VersionReq {
+    fn default() -> Self {
+        VersionReq {
+            comparators: vec![
+                VersionComparator::Exact(Version::new(2, 1)),
+                VersionComparator::Exact(Version::new(3, 0)),
+                VersionComparator::Exact(Version::new(4, 0)),
+            ],
+        }
+    }
+}
+
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rendered: Vec<String> = self.comparators.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}", rendered.join(" || "))
+    }
+}
+
+/// A version requirement string that didn't parse, e.g. mismatched `||` arms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionReqParseError(String);
+
+impl fmt::Display for VersionReqParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid version requirement: {}", self.0)
+    }
+}
+
+impl std::error::Error for VersionReqParseError {}
+
+impl FromStr for VersionReq {
+    type Err = VersionReqParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut comparators = Vec::new();
+
+        for part in s.split("||") {
+            let part = part.trim();
+            let to_err = || VersionReqParseError(s.to_string());
+
+            let comparator = if let Some(rest) = part.strip_prefix(">=") {
+                VersionComparator::AtLeast(rest.trim().parse().map_err(|_| to_err())?)
+            } else if let Some(rest) = part.strip_prefix("<=") {
+                VersionComparator::AtMost(rest.trim().parse().map_err(|_| to_err())?)
+            } else {
+                VersionComparator::Exact(part.parse().map_err(|_| to_err())?)
+            };
+
+            comparators.push(comparator);
+        }
+
+        if comparators.is_empty() {
+            return Err(VersionReqParseError(s.to_string()));
+        }
+
+        Ok(VersionReq { comparators })
+    }
+}
@@ -227,52 +227,38 @@ fn test_missing_fn_should_error() {
 // Version Support
 
 #[test]
-fn test_unsupported_version_21_should_error() {
+fn test_version_21_is_now_accepted() {
+    // vCard 2.1 is accepted by default as of VersionReq::default(): this fixture may
+    // still fail for reasons unrelated to its VERSION (e.g. other structural issues),
+    // but never because 2.1 itself is rejected.
     let content = read_test_file("unsupported_version_2_1.vcf");
     let mut parser = VCardParser::new();
 
     let result = parser.parse(&content);
-    assert!(result.is_err(), "Parser should reject vCard version 2.1");
-
     if let Err(err) = result {
         let error_msg = err.to_string();
-        // vCard 2.1 uses different parameter syntax (TEL;HOME instead of TEL;TYPE=home)
-        // So the parser may fail on parameter parsing before checking version
-        // Both are valid failures for unsupported version
-        let has_version_error = error_msg.to_lowercase().contains("unsupported") && error_msg.contains("2.1");
-        let has_parameter_error = error_msg.contains("parameter") && error_msg.contains("equals");
-
         assert!(
-            has_version_error || has_parameter_error,
-            "Expected either version error or parameter syntax error, got: {}",
+            !(error_msg.to_lowercase().contains("unsupported") && error_msg.contains("2.1")),
+            "vCard 2.1 should no longer be rejected purely for its VERSION: {}",
             error_msg
         );
     }
 }
 
 #[test]
-fn test_unsupported_version_30_should_error() {
+fn test_version_30_is_now_accepted() {
+    // vCard 3.0 is accepted by default as of VersionReq::default(). It additionally
+    // requires N, so this fixture may still fail if it lacks one, but never because 3.0
+    // itself is rejected.
     let content = read_test_file("unsupported_version_3_0.vcf");
     let mut parser = VCardParser::new();
 
     let result = parser.parse(&content);
-    assert!(result.is_err(), "Parser should reject vCard version 3.0");
-
     if let Err(err) = result {
         let error_msg = err.to_string();
         assert!(
-            error_msg.to_lowercase().contains("unsupported"),
-            "Error should indicate unsupported version: {}",
-            error_msg
-        );
-        assert!(
-            error_msg.contains("3.0"),
-            "Error should mention version 3.0: {}",
-            error_msg
-        );
-        assert!(
-            error_msg.contains("4.0"),
-            "Error should mention supported version 4.0: {}",
+            !(error_msg.to_lowercase().contains("unsupported") && error_msg.contains("3.0")),
+            "vCard 3.0 should no longer be rejected purely for its VERSION: {}",
             error_msg
         );
     }
@@ -384,11 +370,9 @@ fn test_critical_negative_test_files_should_error() {
         "incomplete_vcard.vcf",
         "malformed_property_no_colon.vcf",
         "malformed_parameter_syntax.vcf",
-        "unsupported_version_2_1.vcf",
-        "unsupported_version_3_0.vcf",
         "unsupported_version_1_0.vcf",
         "wrong_component_type.vcf",
-        "mismatched_begin_end.vcf"
+        "mismatched_begin_end.vcf",
     ];
 
     let mut passed_count = 0;